@@ -26,22 +26,36 @@ use crate::runner::{BoxedRunner, Output};
 pub use _tuple::tuple;
 use bevy::prelude::Reflect;
 pub use map::Map;
+pub use rng::ActionRng;
 pub use remake::Remake;
 
 pub mod once;
 pub mod wait;
 pub mod delay;
 pub mod switch;
+pub mod selector;
 pub mod seed;
 pub mod through;
 pub mod pipe;
 pub mod inspect;
 pub mod sequence;
 pub mod omit;
+pub mod timeout;
+pub mod throttle;
+pub mod retry;
+pub mod repeat;
+pub mod or_else;
+pub mod branch;
+pub mod interrupt;
+pub mod skip;
+pub mod finally;
+pub mod gated;
+pub mod tap;
 #[path = "action/tuple.rs"]
 mod _tuple;
 mod map;
 mod remake;
+mod rng;
 #[cfg(feature = "effect")]
 #[cfg_attr(docsrs, doc(cfg(feature = "effect")))]
 pub mod side_effect;
@@ -0,0 +1,281 @@
+//! `branch` creates actions that choose which action to run at runtime.
+
+use crate::action::sequence::BoxedAction;
+use crate::action::{Action, ActionRng};
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{IntoSystem, System, World};
+
+/// Runs `action_true` if `condition` returns true, otherwise runs `action_false`.
+///
+/// The condition is evaluated only once, on the first tick of this action,
+/// and the chosen action starts within the same frame.
+/// The action that was not chosen is dropped without being run.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Hp(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, branch::either_if(
+///         |hp: Res<Hp>| hp.0 < 10,
+///         once::run(|| println!("run away")),
+///         once::run(|| println!("attack")),
+///     )).await;
+/// });
+/// ```
+pub fn either_if<Sys, M, I1, I2, O>(
+    condition: Sys,
+    action_true: impl Into<Action<I1, O>> + Send + Sync + 'static,
+    action_false: impl Into<Action<I2, O>> + Send + Sync + 'static,
+) -> ActionSeed<(), O>
+where
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+    I1: 'static,
+    I2: 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| {
+        let action_true = action_true.into();
+        let action_false = action_false.into();
+        EitherIfRunner {
+            condition: IntoSystem::into_system(condition),
+            branches: Some((
+                Box::new(move |output| action_true.create_runner(output)),
+                Box::new(move |output| action_false.create_runner(output)),
+            )),
+            runner: None,
+            output,
+        }
+    })
+}
+
+type CreateRunner<O> = Box<dyn FnOnce(Output<O>) -> BoxedRunner>;
+
+struct EitherIfRunner<Sys, O> {
+    condition: Sys,
+    branches: Option<(CreateRunner<O>, CreateRunner<O>)>,
+    runner: Option<BoxedRunner>,
+    output: Output<O>,
+}
+
+impl<Sys, O> Runner for EitherIfRunner<Sys, O>
+where
+    Sys: System<In=(), Out=bool>,
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if let Some((action_true, action_false)) = self.branches.take() {
+            self.condition.initialize(world);
+            let condition = self.condition.run((), world);
+            self.condition.apply_deferred(world);
+            let create_runner = if condition { action_true } else { action_false };
+            self.runner.replace(create_runner(self.output.clone()));
+        }
+
+        self.runner
+            .as_mut()
+            .map(|runner| runner.run(world, token))
+            .unwrap_or(RunnerIs::Canceled)
+    }
+}
+
+/// Runs one of the `actions` chosen randomly according to their weights.
+///
+/// The choice is made on the first tick of this action using [`ActionRng`],
+/// and the chosen action starts within the same frame.
+/// Actions with a weight of 0 are never chosen.
+///
+/// ## Panics
+///
+/// Panics if any weight is negative or not finite, or if the sum of the weights is not greater than 0.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, branch::pick_random([
+///         (3.0, once::run(|| println!("patrol")).omit_input()),
+///         (1.0, delay::frames().with(60).omit_input()),
+///     ])).await;
+/// });
+/// ```
+pub fn pick_random<Actions, O>(actions: Actions) -> ActionSeed<(), O>
+where
+    Actions: IntoIterator<Item=(f32, BoxedAction<O>)>,
+    O: 'static,
+{
+    let actions = actions.into_iter().collect::<Vec<_>>();
+    for (weight, _) in actions.iter() {
+        assert!(weight.is_finite() && 0. <= *weight, "The weights passed to `pick_random` must be finite and non-negative, but got {weight}");
+    }
+    let total = actions.iter().map(|(weight, _)| weight).sum::<f32>();
+    if total <= 0. {
+        panic!("The sum of the weights must be greater than 0");
+    }
+    ActionSeed::new(move |_, output| PickRandomRunner {
+        actions,
+        total,
+        runner: None,
+        output,
+    })
+}
+
+struct PickRandomRunner<O> {
+    actions: Vec<(f32, BoxedAction<O>)>,
+    total: f32,
+    runner: Option<BoxedRunner>,
+    output: Output<O>,
+}
+
+impl<O> PickRandomRunner<O>
+where
+    O: 'static,
+{
+    fn pick(&mut self, world: &mut World) -> BoxedAction<O> {
+        let mut rest = world
+            .get_resource_or_insert_with(ActionRng::default)
+            .next_f32() * self.total;
+        let mut index = None;
+        for (i, (weight, _)) in self.actions.iter().enumerate() {
+            if *weight <= 0. {
+                continue;
+            }
+            index.replace(i);
+            if rest < *weight {
+                break;
+            }
+            rest -= weight;
+        }
+        let index = index.expect("There is no action with a positive weight");
+        self.actions.swap_remove(index).1
+    }
+}
+
+impl<O> Runner for PickRandomRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if self.runner.is_none() {
+            let seed = self.pick(world);
+            self.actions.clear();
+            self.runner.replace(seed.with(()).create_runner(self.output.clone()));
+        }
+
+        self.runner
+            .as_mut()
+            .map(|runner| runner.run(world, token))
+            .unwrap_or(RunnerIs::Canceled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{branch, delay, once, ActionRng};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Res, ResMut};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn set_count(num: usize) -> crate::prelude::ActionSeed {
+        once::run(move |mut count: ResMut<Count>| {
+            count.0 = num;
+        })
+    }
+
+    #[test]
+    fn run_true_branch() {
+        let mut app = test_app();
+        app.set_bool(true);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, branch::either_if(|b: Res<Bool>| **b, set_count(1), set_count(2))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn condition_is_evaluated_only_on_first_tick() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, branch::either_if(
+                    |b: Res<Bool>| **b,
+                    set_count(1),
+                    delay::frames().with(1).then(set_count(2)),
+                )).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.set_bool(true);
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn pick_random_by_weight() {
+        let mut app = test_app();
+        app.insert_resource(ActionRng::new(|| 0.5));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, branch::pick_random([
+                    (1.0, set_count(1)),
+                    (2.0, set_count(2)),
+                    (1.0, set_count(3)),
+                ])).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn never_pick_zero_weight() {
+        let mut app = test_app();
+        app.insert_resource(ActionRng::new(|| 0.999));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, branch::pick_random([
+                    (0.0, set_count(1)),
+                    (1.0, set_count(2)),
+                    (0.0, set_count(3)),
+                ])).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_if_weight_is_negative() {
+        let _ = branch::pick_random([
+            (-1.0, set_count(1)),
+            (2.0, set_count(2)),
+        ]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_if_weight_is_nan() {
+        let _ = branch::pick_random([
+            (f32::NAN, set_count(1)),
+            (1.0, set_count(2)),
+        ]);
+    }
+}
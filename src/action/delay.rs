@@ -1,13 +1,23 @@
 //! `delay` creates a task that delay the application.
 
-use crate::action::wait;
-use crate::prelude::ActionSeed;
-use bevy::prelude::{In, Local, Res, TimerMode};
-use bevy::time::{Time, Timer};
+use crate::action::{wait, Action, ActionRng};
+use crate::prelude::{ActionSeed, BoxedRunner, Progress, RunnerIs, Then};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{Commands, Component, Entity, In, Local, Res, TimerMode, World};
+use bevy::time::{Fixed, Time, Timer};
+use std::ops::{DerefMut, Range};
 use std::time::Duration;
 
+pub mod time;
+
 /// Delays by the specified amount of time.
 ///
+/// This uses the default [`Time`] of the schedule, which is [`Time<Virtual>`](bevy::time::Virtual) in `Update`,
+/// so the delay already stops while the virtual time is paused and follows its relative speed,
+/// such as for slow-motion effects.
+/// See [`delay::time`](mod@self::time) to choose the clock explicitly,
+/// for example [`delay::time::real`](self::time::real) to ignore pausing and scaling.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -31,8 +41,54 @@ pub fn time() -> ActionSeed<Duration> {
     )
 }
 
+/// Delays by the specified amount of time, publishing the elapsed fraction into [`Progress<M>`] every tick.
+///
+/// The progress is `elapsed / duration`, and reaches `1.0` when the delay completes,
+/// so fades and charge bars can observe the delay without duplicating the timer.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Charge;
+///
+/// fn charge_bar(progress: Option<Res<Progress<Charge>>>){
+///     if let Some(progress) = progress {
+///         println!("charge: {}", progress.get());
+///     }
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::time_with_progress::<Charge>().with(Duration::from_secs(2))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn time_with_progress<M>() -> ActionSeed<Duration>
+where
+    M: Send + Sync + 'static,
+{
+    wait::until(
+        |In(duration): In<Duration>, mut commands: Commands, mut timer: Local<Option<Timer>>, time: Res<Time>| {
+            let timer = timer.get_or_insert_with(|| Timer::new(duration, TimerMode::Once));
+            let finished = timer.tick(time.delta()).just_finished();
+            commands.insert_resource(Progress::<M>::new(if finished { 1. } else { timer.fraction() }));
+            finished
+        }
+    )
+}
+
 /// Delays the specified number of frames.
 ///
+/// The frames are counted as ticks of the schedule in which the action is running,
+/// independently of [`Time`], so use this instead of [`time`] for frame-precise sequencing,
+/// such as keeping a hitbox for exactly 3 frames.
+///
+/// The number of frames is the input of this action, so it can also be piped
+/// from the output of the previous action, such as a stagger computed per entity.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -41,6 +97,11 @@ pub fn time() -> ActionSeed<Duration> {
 ///
 /// Reactor::schedule(|task| async move{
 ///     task.will(Update, delay::frames().with(30)).await;
+///
+///     let index = 3;
+///     task.will(Update, once::run(move || index * 10)
+///         .pipe(delay::frames())
+///     ).await;
 /// });
 /// ```
 #[inline(always)]
@@ -51,15 +112,286 @@ pub fn frames() -> ActionSeed<usize> {
     })
 }
 
+/// Yields for a single tick of the schedule, and then completes.
+///
+/// This is the same as `delay::frames().with(1)` without running a system,
+/// so it can be used to deliberately spread work over frames.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     for chunk in [[1, 2], [3, 4]] {
+///         task.will(Update, once::run(move || println!("{chunk:?}"))).await;
+///         task.will(Update, delay::next_frame()).await;
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn next_frame() -> ActionSeed {
+    ActionSeed::new(|_, output| NextFrameRunner {
+        yielded: false,
+        output,
+    })
+}
+
+struct NextFrameRunner {
+    yielded: bool,
+    output: Output<()>,
+}
+
+impl Runner for NextFrameRunner {
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        if self.yielded {
+            self.output.set(());
+            RunnerIs::Completed
+        } else {
+            self.yielded = true;
+            RunnerIs::Running
+        }
+    }
+}
+
+/// Delays the specified number of `FixedUpdate` ticks.
+///
+/// The ticks are counted by the elapsed [`Time<Fixed>`], so it works correctly
+/// even if the action is running in `Update`, where zero or more fixed ticks can happen per frame.
+/// Only the ticks after the action started are counted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::fixed_ticks().with(60)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn fixed_ticks() -> ActionSeed<usize> {
+    wait::until(
+        |In(ticks): In<usize>, mut state: Local<Option<(Duration, usize)>>, time: Res<Time<Fixed>>| {
+            let (last, count) = state.get_or_insert((time.elapsed(), 0));
+            let timestep = time.timestep().as_nanos();
+            if 0 < timestep {
+                *count += ((time.elapsed() - *last).as_nanos() / timestep) as usize;
+            }
+            *last = time.elapsed();
+            ticks <= *count
+        }
+    )
+}
+
+/// Delays by a random amount of time in the specified range.
+///
+/// The duration is drawn from [`ActionRng`] each time the action runs,
+/// so inserting [`ActionRng`] with a fixed generator makes it deterministic in tests.
+/// If the range is empty, it delays by the start of the range.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::{World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     loop {
+///         task.will(Update, delay::random().with(Duration::from_millis(500)..Duration::from_secs(2))).await;
+///         println!("think");
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn random() -> ActionSeed<Range<Duration>> {
+    wait::until(
+        |In(range): In<Range<Duration>>, world: &mut World, mut timer: Local<Option<Timer>>| {
+            let timer = timer.get_or_insert_with(|| {
+                let duration = if range.start < range.end {
+                    let t = world.get_resource_or_insert_with(ActionRng::default).next_f32();
+                    range.start + (range.end - range.start).mul_f32(t)
+                } else {
+                    range.start
+                };
+                Timer::new(duration, TimerMode::Once)
+            });
+            timer.tick(world.resource::<Time>().delta()).just_finished()
+        }
+    )
+}
+
+/// Delays until [`Time::elapsed`] reaches the specified target.
+///
+/// Unlike [`time`], the target is an absolute point of time since the startup,
+/// so multiple reactors can be synchronized to the same beat regardless of when they started.
+/// It completes immediately if the target has already been reached.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::{World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// const BEAT: Duration = Duration::from_millis(500);
+///
+/// Reactor::schedule(|task| async move{
+///     for beat in 1..=8 {
+///         task.will(Update, delay::until().with(BEAT * beat)).await;
+///         println!("beat {beat}");
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn until() -> ActionSeed<Duration> {
+    wait::until(|In(target): In<Duration>, time: Res<Time>| {
+        target <= time.elapsed()
+    })
+}
+
+/// Delays until the [`Timer`] of the component `C` attached to the entity passed as the input has finished.
+///
+/// The timer is ticked by this action, so other systems can pause, extend, or shorten the delay
+/// while it is running by mutating the component, for example with [`Timer::pause`] or [`Timer::set_duration`].
+/// It is the same as [`wait::timer::finished_on`](crate::prelude::wait::timer::finished_on).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component, Deref, DerefMut)]
+/// struct PhaseTimer(Timer);
+///
+/// Reactor::schedule(|task| async move{
+///     let boss = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn(PhaseTimer(Timer::from_seconds(30., TimerMode::Once))).id()
+///     })).await;
+///     task.will(Update, delay::timer::<PhaseTimer>().with(boss)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn timer<C>() -> ActionSeed<Entity>
+where
+    C: Component + DerefMut<Target=Timer>,
+{
+    wait::timer::finished_on::<C>()
+}
+
+/// Delays by the specified amount of time unless `interrupt` completes first.
+///
+/// The output is `Ok(())` if the delay has elapsed,
+/// or `Err` holding the remaining time if `interrupt` completed first,
+/// so the remaining time can be recovered, for example to resume the delay later.
+/// If both happen in the same frame, the delay takes precedence.
+/// To recover the remaining time when the delay is raced by other combinators such as [`Timeout`](crate::prelude::Timeout),
+/// use [`DelayTime::remainder`](self::time::DelayTime::remainder) instead.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, delay::interruptible(
+///         Duration::from_secs(3),
+///         wait::input::just_pressed().with(KeyCode::Escape),
+///     )).await;
+///     if let Err(remaining) = result {
+///         println!("interrupted with {remaining:?} left");
+///     }
+/// });
+/// ```
+pub fn interruptible<I, O>(
+    duration: Duration,
+    interrupt: impl Into<Action<I, O>> + Send + Sync + 'static,
+) -> ActionSeed<(), Result<(), Duration>>
+where
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| InterruptibleRunner {
+        timer: Timer::new(duration, TimerMode::Once),
+        interrupt: interrupt.into().create_runner(Output::default()),
+        output,
+    })
+}
+
+struct InterruptibleRunner {
+    timer: Timer,
+    interrupt: BoxedRunner,
+    output: Output<Result<(), Duration>>,
+}
+
+impl Runner for InterruptibleRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if self.timer.tick(world.resource::<Time>().delta()).finished() {
+            self.output.set(Ok(()));
+            return RunnerIs::Completed;
+        }
+        match self.interrupt.run(world, token) {
+            RunnerIs::Completed => {
+                self.output.set(Err(self.timer.remaining()));
+                RunnerIs::Completed
+            }
+            other => other,
+        }
+    }
+}
+
+/// Runs `action` after the specified amount of time has elapsed.
+///
+/// This is the same as `delay::time().with(duration).then(action)`,
+/// but as a single [`ActionSeed`], it can be stored in collections of actions
+/// or embedded into `sequence!` and `pipe` chains.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::{World, Update};
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::sequence;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, sequence![
+///         once::run(|| println!("3")),
+///         delay::after(Duration::from_secs(1), once::run(|| println!("2"))),
+///         delay::after(Duration::from_secs(1), once::run(|| println!("1"))),
+///     ]).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn after<I, O>(
+    duration: Duration,
+    action: impl Into<Action<I, O>> + Send + Sync + 'static,
+) -> ActionSeed<(), O>
+where
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::define(move |_| time().with(duration).then(action))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::action::{delay, once};
-    use crate::prelude::{Reactor, Then};
-    use crate::tests::test_app;
-    use bevy::app::{AppExit, First, Startup};
-    use bevy::prelude::{Commands, Events};
+    use crate::action::{delay, once, ActionRng};
+    use crate::prelude::{Pipe, Progress, Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{AppExit, First, Startup, Update};
+    use bevy::prelude::{Commands, Component, Deref, DerefMut, Events, ResMut, TimerMode};
+    use bevy::time::{Fixed, Time, TimeUpdateStrategy, Timer, Virtual};
     use bevy_test_helper::event::DirectEvents;
+    use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
 
     #[test]
     fn delay_1frame() {
@@ -105,4 +437,236 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
+
+    #[test]
+    fn after_runs_action_after_delay() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::after(Duration::from_millis(300), increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn frames_are_counted_even_if_time_paused() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(3).then(increment_count())).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn delay_frames_piped_from_previous_output() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| 2)
+                    .pipe(delay::frames())
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn until_elapsed_reaches_target() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::until().with(Duration::from_millis(300)).then(increment_count())).await;
+                task.will(Update, delay::until().with(Duration::from_millis(100)).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn random_delay_uses_action_rng() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.insert_resource(ActionRng::new(|| 0.5));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::random()
+                    .with(Duration::from_millis(200)..Duration::from_millis(1000))
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        for _ in 0..6 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn time_follows_virtual_relative_speed() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.world_mut().resource_mut::<Time<Virtual>>().set_relative_speed(2.0);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time().with(Duration::from_millis(400)).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn fixed_ticks_counted_in_update() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(50)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::fixed_ticks().with(4).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn time_with_progress_publishes_fraction() {
+        struct Charge;
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time_with_progress::<Charge>().with(Duration::from_millis(400))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        let progress = app.world().resource::<Progress<Charge>>().get();
+        assert!(0. < progress && progress < 1.);
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().resource::<Progress<Charge>>().is_completed());
+    }
+
+    #[test]
+    fn interruptible_outputs_remaining_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, delay::interruptible(
+                    Duration::from_millis(1000),
+                    delay::frames().with(3),
+                )).await;
+                task.will(Update, once::run(move |mut count: ResMut<Count>| {
+                    count.0 = result.unwrap_err().as_millis() as usize;
+                })).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        let remaining = app.world().resource::<Count>().0;
+        assert!(0 < remaining && remaining < 1000);
+    }
+
+    #[test]
+    fn interruptible_ok_if_elapsed() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, delay::interruptible(
+                    Duration::from_millis(300),
+                    delay::frames().with(100),
+                )).await;
+                if result.is_ok() {
+                    task.will(Update, increment_count()).await;
+                }
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn timer_shortened_from_outside() {
+        #[derive(Component, Deref, DerefMut)]
+        struct PhaseTimer(Timer);
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let boss = app.world_mut().spawn(PhaseTimer(Timer::new(Duration::from_secs(10), TimerMode::Once))).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, delay::timer::<PhaseTimer>().with(boss).then(increment_count())).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.world_mut().get_mut::<PhaseTimer>(boss).unwrap().set_duration(Duration::from_millis(300));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn next_frame_yields_single_tick() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, increment_count()
+                    .then(delay::next_frame())
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
 }
@@ -0,0 +1,436 @@
+//! [`delay::time`](mod@crate::prelude::delay::time) provides the delays that use a specific clock.
+//!
+//! [`delay::time()`](crate::prelude::delay::time()) uses the default [`Time`] of the schedule,
+//! which is [`Time<Virtual>`] in `Update`, so it stops while the virtual time is paused.
+//! The actions in this module let you choose the clock explicitly,
+//! so for example UI reactors can keep running on the real time while the game is paused.
+//!
+//! [`delay::time::of`](of) creates [`DelayTime`], the builder of the delay with options.
+
+use crate::action::{wait, Action, Remake};
+use crate::prelude::{ActionSeed, RunnerIs};
+use crate::runner::{BoxedRunner, CancellationHandlers, Output, Runner};
+use bevy::prelude::{In, Local, Res, TimerMode, World};
+use bevy::time::{Fixed, Real, Time, Timer, Virtual};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Creates [`DelayTime`] which delays by `duration`.
+///
+/// Without any options, it is the same as `delay::time().with(duration)`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(FixedUpdate, delay::time::of(Duration::from_secs(1)).scaled()).await;
+/// });
+/// ```
+#[inline(always)]
+pub const fn of(duration: Duration) -> DelayTime {
+    DelayTime {
+        duration,
+        scaled: false,
+        remainder: None,
+    }
+}
+
+/// The builder of the delay created by [`delay::time::of`](of).
+///
+/// It can be passed to `task.will` directly, and combined with other actions
+/// such as [`Then`](crate::prelude::Then) and [`Timeout`](crate::prelude::Timeout) like [`ActionSeed`].
+pub struct DelayTime {
+    duration: Duration,
+    scaled: bool,
+    remainder: Option<Remainder>,
+}
+
+impl DelayTime {
+    /// Measures the delay by [`Time<Virtual>`] in any schedule,
+    /// so it stops advancing while the virtual time is paused and follows its relative speed.
+    ///
+    /// By default, the delay uses the default [`Time`] of the schedule,
+    /// which is [`Time<Virtual>`] in `Update` but [`Time<Fixed>`] in `FixedUpdate`.
+    #[inline(always)]
+    pub const fn scaled(mut self) -> Self {
+        self.scaled = true;
+        self
+    }
+
+    /// Records the remaining time of the delay into `remainder` every tick.
+    ///
+    /// Combinators such as [`Timeout::timeout`](crate::prelude::Timeout::timeout) and
+    /// [`interrupt_on`](crate::prelude::interrupt_on) drop the delay when they win the race,
+    /// so the remaining time can be recovered from [`Remainder::result`] afterward.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    /// use bevy_flurx::prelude::delay::time::Remainder;
+    ///
+    /// #[derive(Event, Clone)]
+    /// struct Skip;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let remainder = Remainder::default();
+    ///     task.will(Update, interrupt_on::<Skip, _, _>(
+    ///         delay::time::of(Duration::from_secs(3)).remainder(&remainder)
+    ///     )).await;
+    ///     if let Err(remaining) = remainder.result() {
+    ///         println!("skipped with {remaining:?} left");
+    ///     }
+    /// });
+    /// ```
+    #[inline]
+    pub fn remainder(mut self, remainder: &Remainder) -> Self {
+        self.remainder.replace(remainder.clone());
+        self
+    }
+}
+
+/// The remaining time of the delay recorded by [`DelayTime::remainder`].
+///
+/// It is a handle to the shared value, so it can be cloned and read after the delay has been dropped.
+#[derive(Clone, Default, Debug)]
+pub struct Remainder(Arc<Mutex<Duration>>);
+
+impl Remainder {
+    /// Returns the remaining time as of the last tick of the delay.
+    ///
+    /// It is the whole duration once the delay starts, and zero after the delay has elapsed.
+    #[inline]
+    pub fn get(&self) -> Duration {
+        *self.0.lock().unwrap()
+    }
+
+    /// Returns `Ok(())` if the delay has elapsed,
+    /// otherwise `Err` holding the remaining time, such as when the delay has been canceled.
+    #[inline]
+    pub fn result(&self) -> Result<(), Duration> {
+        let remaining = self.get();
+        if remaining.is_zero() {
+            Ok(())
+        } else {
+            Err(remaining)
+        }
+    }
+
+    fn set(&self, remaining: Duration) {
+        *self.0.lock().unwrap() = remaining;
+    }
+}
+
+impl From<DelayTime> for ActionSeed {
+    fn from(delay: DelayTime) -> Self {
+        ActionSeed::new(move |_, output| {
+            if let Some(remainder) = delay.remainder.as_ref() {
+                remainder.set(delay.duration);
+            }
+            DelayTimeRunner {
+                timer: Timer::new(delay.duration, TimerMode::Once),
+                scaled: delay.scaled,
+                last_elapsed: None,
+                remainder: delay.remainder,
+                output,
+            }
+        })
+    }
+}
+
+impl From<DelayTime> for Action {
+    #[inline]
+    fn from(delay: DelayTime) -> Self {
+        ActionSeed::from(delay).into()
+    }
+}
+
+impl<O2> Remake<(), (), O2, ActionSeed<(), O2>> for DelayTime
+where
+    O2: 'static,
+{
+    #[inline]
+    fn remake<F, R>(self, f: F) -> ActionSeed<(), O2>
+    where
+        F: FnOnce(BoxedRunner, Output<()>, Output<O2>) -> R + Send + Sync + 'static,
+        R: Runner + 'static,
+    {
+        ActionSeed::from(self).remake(f)
+    }
+}
+
+struct DelayTimeRunner {
+    timer: Timer,
+    scaled: bool,
+    last_elapsed: Option<Duration>,
+    remainder: Option<Remainder>,
+    output: Output<()>,
+}
+
+impl Runner for DelayTimeRunner {
+    fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        let delta = if self.scaled {
+            // The elapsed time is compared instead of the delta,
+            // since the schedule such as `FixedUpdate` can run several times per frame.
+            let time = world.resource::<Time<Virtual>>();
+            match self.last_elapsed.replace(time.elapsed()) {
+                Some(last) => time.elapsed() - last,
+                None => time.delta(),
+            }
+        } else {
+            world.resource::<Time>().delta()
+        };
+        let finished = self.timer.tick(delta).finished();
+        if let Some(remainder) = self.remainder.as_ref() {
+            remainder.set(self.timer.remaining());
+        }
+        if finished {
+            self.output.set(());
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+/// Delays by the specified amount of [`Time<Real>`].
+///
+/// The real time is not affected by pausing or scaling the virtual time.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::time::real().with(Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn real() -> ActionSeed<Duration> {
+    tick::<Real>()
+}
+
+/// Delays by the specified amount of [`Time<Virtual>`].
+///
+/// It stops advancing while the virtual time is paused, and follows its relative speed.
+/// Since `virtual` is a reserved keyword, this is named `virt`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::time::virt().with(Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn virt() -> ActionSeed<Duration> {
+    tick::<Virtual>()
+}
+
+/// Delays by the specified amount of [`Time<Fixed>`].
+///
+/// The delay is measured by the elapsed fixed time, so it completes in the first tick after
+/// the fixed update loop has advanced by the duration, even if the action runs in `Update`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::time::fixed().with(Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn fixed() -> ActionSeed<Duration> {
+    wait::until(
+        |In(duration): In<Duration>, mut start: Local<Option<Duration>>, time: Res<Time<Fixed>>| {
+            let start = *start.get_or_insert(time.elapsed());
+            duration <= time.elapsed() - start
+        }
+    )
+}
+
+fn tick<C>() -> ActionSeed<Duration>
+where
+    C: Default + Send + Sync + 'static,
+{
+    wait::until(
+        |In(duration): In<Duration>, mut timer: Local<Option<Timer>>, time: Res<Time<C>>| {
+            timer
+                .get_or_insert_with(|| Timer::new(duration, TimerMode::Once))
+                .tick(time.delta())
+                .just_finished()
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::delay;
+    use crate::action::delay::time::Remainder;
+    use crate::prelude::{interrupt_on, Reactor, Then, Timeout};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy::time::{Time, TimeUpdateStrategy, Virtual};
+    use bevy_test_helper::event::{DirectEvents, TestEvent1};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn real_time_advances_while_virtual_paused() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time::real().with(Duration::from_millis(300)).then(increment_count())).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn virtual_time_stops_while_paused() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time::virt().with(Duration::from_millis(300)).then(increment_count())).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn delay_of_duration() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time::of(Duration::from_millis(300)).then(increment_count())).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn scaled_delay_stops_while_paused_and_follows_speed() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time::of(Duration::from_millis(300)).scaled().then(increment_count())).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        app.world_mut().resource_mut::<Time<Virtual>>().set_relative_speed(3.0);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn remainder_after_timeout() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let remainder = Remainder::default();
+        let r = remainder.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let r = r.clone();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, delay::time::of(Duration::from_millis(1000))
+                    .remainder(&r)
+                    .timeout(Duration::from_millis(300)),
+                ).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        let remaining = remainder.result().unwrap_err();
+        assert!(Duration::ZERO < remaining && remaining < Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn remainder_after_interrupted() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let remainder = Remainder::default();
+        let r = remainder.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let r = r.clone();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, interrupt_on::<TestEvent1, _, _>(
+                    delay::time::of(Duration::from_millis(1000)).remainder(&r)
+                )).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.send(TestEvent1);
+        app.update();
+        let remaining = remainder.result().unwrap_err();
+        assert!(Duration::ZERO < remaining && remaining < Duration::from_millis(1000));
+        app.update();
+        assert_eq!(remainder.result(), Err(remaining));
+    }
+
+    #[test]
+    fn remainder_ok_if_elapsed() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let remainder = Remainder::default();
+        let r = remainder.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let r = r.clone();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, delay::time::of(Duration::from_millis(300)).remainder(&r)).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(remainder.result(), Ok(()));
+    }
+}
@@ -0,0 +1,266 @@
+//! Provides the mechanism to run a cleanup action however an action ends.
+//!
+//! trait
+//!
+//! - [`Finally`]
+
+use crate::action::remake::Remake;
+use crate::action::Action;
+use crate::prelude::{CancellationHandlers, CancellationId};
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Provides the mechanism to run a cleanup action when an action ends.
+pub trait Finally<I1, O1, ActionOrSeed> {
+    /// Creates an action that runs `cleanup` after this action,
+    /// whether it completed or its reactor was canceled.
+    ///
+    /// If this action completes, `cleanup` is run to completion and then
+    /// the output of this action is output as is.
+    ///
+    /// If the reactor is canceled while this action is running,
+    /// for example by [`RunnerIs::Canceled`] or by despawning the reactor entity,
+    /// `cleanup` is run only for a single tick via [`CancellationHandlers`],
+    /// so it should complete immediately, like the [`once`](crate::prelude::once) actions.
+    ///
+    /// Note that `cleanup` is not run if this action is dropped without the reactor being canceled,
+    /// such as when it loses the race in [`wait::either`](crate::prelude::wait::either) or
+    /// [`wait::any`](crate::prelude::wait::any), because a dropped runner has no access to the [`World`].
+    /// In that case, `cleanup` is not run even if the reactor is canceled later.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct Cutscene;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, once::switch::on::<Cutscene>()
+    ///         .then(delay::frames().with(300))
+    ///         .finally(once::switch::off::<Cutscene>())
+    ///     ).await;
+    /// });
+    /// ```
+    fn finally<I2, O2>(self, cleanup: impl Into<Action<I2, O2>> + Send + Sync + 'static) -> ActionOrSeed
+    where
+        I2: 'static,
+        O2: 'static;
+}
+
+impl<I1, O1, A, Re> Finally<I1, O1, A> for Re
+where
+    I1: 'static,
+    O1: 'static,
+    Re: Remake<I1, O1, O1, A> + 'static,
+{
+    #[inline]
+    fn finally<I2, O2>(self, cleanup: impl Into<Action<I2, O2>> + Send + Sync + 'static) -> A
+    where
+        I2: 'static,
+        O2: 'static,
+    {
+        self.remake(|r1, o1, output| FinallyRunner {
+            r1,
+            o1,
+            r2: None,
+            cleanup: Arc::new(Mutex::new(Some(cleanup))),
+            cancellation_id: None,
+            dropped: Arc::new(AtomicBool::new(false)),
+            reactor_canceled: None,
+            output,
+        })
+    }
+}
+
+struct FinallyRunner<O, C> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    r2: Option<BoxedRunner>,
+    cleanup: Arc<Mutex<Option<C>>>,
+    cancellation_id: Option<CancellationId>,
+    dropped: Arc<AtomicBool>,
+    reactor_canceled: Option<Arc<AtomicBool>>,
+    output: Output<O>,
+}
+
+impl<O, C> Drop for FinallyRunner<O, C> {
+    fn drop(&mut self) {
+        // The handler stays registered after this runner is dropped,
+        // so disable it unless the runner is dropped because the reactor is canceled.
+        if self.reactor_canceled.as_ref().is_some_and(|canceled| !canceled.load(Ordering::Relaxed)) {
+            self.dropped.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+fn take_cleanup<C, I2, O2>(cleanup: &Mutex<Option<C>>) -> Option<Action<I2, O2>>
+where
+    C: Into<Action<I2, O2>>,
+{
+    cleanup
+        .lock()
+        .ok()
+        .and_then(|mut cleanup| cleanup.take())
+        .map(C::into)
+}
+
+impl<O, C, I2, O2> Runner for FinallyRunner<O, C>
+where
+    O: 'static,
+    C: Into<Action<I2, O2>> + Send + Sync + 'static,
+    I2: 'static,
+    O2: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if self.r2.is_none() {
+            if self.cancellation_id.is_none() {
+                let cleanup = self.cleanup.clone();
+                let dropped = self.dropped.clone();
+                self.reactor_canceled.replace(token.canceled_flag());
+                self.cancellation_id.replace(token.register_once(move |world: &mut World| {
+                    if dropped.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    if let Some(action) = take_cleanup(&cleanup) {
+                        let mut runner = action.create_runner(Output::default());
+                        runner.run(world, &mut CancellationHandlers::default());
+                    }
+                }));
+            }
+            match self.r1.run(world, token) {
+                RunnerIs::Completed => {}
+                other => return other,
+            }
+            if let Some(id) = self.cancellation_id.take() {
+                token.unregister(&id);
+            }
+            let Some(action) = take_cleanup(&self.cleanup) else {
+                return RunnerIs::Canceled;
+            };
+            self.r2.replace(action.create_runner(Output::default()));
+        }
+
+        match self.r2.as_mut().unwrap().run(world, token) {
+            RunnerIs::Completed => {
+                let o = self.o1.take().expect("An output value hasn't been set!!!");
+                self.output.set(o);
+                RunnerIs::Completed
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::finally::Finally;
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::test_util::test;
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Entity, In, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn run_cleanup_after_completed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| 3)
+                    .finally(increment_count())
+                    .pipe(once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+                        count.0 += num;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(4));
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(4));
+    }
+
+    #[test]
+    fn run_cleanup_if_canceled() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, test::cancel().finally(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_cleanup_if_reactor_despawned() {
+        #[derive(Component)]
+        struct Cancellable;
+
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((
+                Cancellable,
+                Reactor::schedule(|task| async move {
+                    task.will(Update, delay::frames().with(100).finally(increment_count())).await;
+                }),
+            ));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        let reactors = app
+            .world_mut()
+            .query_filtered::<Entity, With<Cancellable>>()
+            .iter(app.world())
+            .collect::<Vec<_>>();
+        for reactor in reactors {
+            app.world_mut().despawn(reactor);
+        }
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn not_run_cleanup_of_either_loser_if_reactor_despawned() {
+        #[derive(Component)]
+        struct Cancellable;
+
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((
+                Cancellable,
+                Reactor::schedule(|task| async move {
+                    task.will(Update, wait::either(
+                        delay::frames().with(100).finally(increment_count()),
+                        once::run(|| {}),
+                    )).await;
+                    task.will(Update, delay::frames().with(100)).await;
+                }),
+            ));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        let reactors = app
+            .world_mut()
+            .query_filtered::<Entity, With<Cancellable>>()
+            .iter(app.world())
+            .collect::<Vec<_>>();
+        for reactor in reactors {
+            app.world_mut().despawn(reactor);
+        }
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+    }
+}
@@ -0,0 +1,97 @@
+//! Provides the mechanism to pause an action with a [`Switch`].
+//!
+//! actions
+//!
+//! - [`gated`]
+
+use crate::action::Action;
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs, Switch};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::World;
+use std::marker::PhantomData;
+
+/// Runs the action only on frames where [`Switch<M>`] is on.
+///
+/// While the switch is off or doesn't exist, the action is not run at all,
+/// so its progress, such as elapsed time or frames, is paused.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Playing;
+///
+/// Reactor::schedule(|task| async move{
+///     // Turning off `Switch<Playing>` pauses the countdown.
+///     task.will(Update, gated::<Playing, _, _>(delay::frames().with(600))).await;
+/// });
+/// ```
+pub fn gated<M, I, O>(action: impl Into<Action<I, O>> + 'static) -> Action<I, O>
+where
+    M: Send + Sync + 'static,
+    I: 'static,
+    O: 'static,
+{
+    let Action(input, seed) = action.into();
+    ActionSeed::new(move |input: I, output| GatedRunner::<M> {
+        r1: seed.with(input).create_runner(output),
+        _m: PhantomData,
+    })
+        .with(input)
+}
+
+struct GatedRunner<M> {
+    r1: BoxedRunner,
+    _m: PhantomData<M>,
+}
+
+impl<M> Runner for GatedRunner<M>
+where
+    M: Send + Sync + 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if world.get_resource::<Switch<M>>().is_some_and(|s| s.is_on()) {
+            self.r1.run(world, token)
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::delay;
+    use crate::action::gated::gated;
+    use crate::prelude::{Reactor, Switch, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    struct Playing;
+
+    #[test]
+    fn pause_while_switch_off() {
+        let mut app = test_app();
+        app.insert_resource(Switch::<Playing>::new(true));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, gated::<Playing, _, _>(delay::frames().with(2).then(increment_count()))).await;
+            }));
+        });
+        app.update();
+        app.resource_mut::<Switch<Playing>>().off();
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<Switch<Playing>>().on();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,168 @@
+//! Provides the mechanism to interrupt an action by an event.
+//!
+//! actions
+//!
+//! - [`interrupt_on`]
+//!
+//! struct
+//!
+//! - [`Interrupted`]
+
+use crate::action::{wait, Action};
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{Event, World};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// The error returned by [`interrupt_on`] if the event was sent before the action completed.
+///
+/// It holds the event that interrupted the action.
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd)]
+pub struct Interrupted<E>(pub E);
+
+impl<E> Display for Interrupted<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the action has been interrupted by an event")
+    }
+}
+
+impl<E: Debug> Error for Interrupted<E> {}
+
+/// Runs the action until it completes or the event `E` is sent.
+///
+/// The output is `Ok` with the output of the action if it completed,
+/// or `Err(`[`Interrupted`]`)` holding the event if the event was sent first.
+/// If both happen in the same frame, the completion of the action takes precedence.
+///
+/// The action is dropped when interrupted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Skip;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, interrupt_on::<Skip, _, _>(
+///         delay::frames().with(600)
+///     )).await;
+///     if result.is_err() {
+///         println!("skipped");
+///     }
+/// });
+/// ```
+pub fn interrupt_on<E, I, O>(
+    action: impl Into<Action<I, O>> + 'static,
+) -> Action<I, Result<O, Interrupted<E>>>
+where
+    E: Event + Clone,
+    I: 'static,
+    O: 'static,
+{
+    let Action(input, seed) = action.into();
+    ActionSeed::new(move |input: I, output| {
+        let o1 = Output::default();
+        let event = Output::default();
+        InterruptRunner {
+            r1: seed.with(input).create_runner(o1.clone()),
+            o1,
+            event_runner: wait::event::read::<E>().with(()).create_runner(event.clone()),
+            event,
+            output,
+        }
+    })
+        .with(input)
+}
+
+struct InterruptRunner<O, E> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    event_runner: BoxedRunner,
+    event: Output<E>,
+    output: Output<Result<O, Interrupted<E>>>,
+}
+
+impl<O, E> Runner for InterruptRunner<O, E>
+where
+    O: 'static,
+    E: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.r1.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Running => {}
+            RunnerIs::Completed => {
+                let o = self.o1.take().expect("An output value hasn't been set!!!");
+                self.output.set(Ok(o));
+                return RunnerIs::Completed;
+            }
+        }
+
+        match self.event_runner.run(world, token) {
+            RunnerIs::Completed => {
+                let event = self.event.take().expect("An output value hasn't been set!!!");
+                self.output.set(Err(Interrupted(event)));
+                RunnerIs::Completed
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::interrupt::{interrupt_on, Interrupted};
+    use crate::action::{delay, once};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::event::{DirectEvents, TestEvent1};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn ok_if_action_completed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, interrupt_on::<TestEvent1, _, _>(delay::frames().with(1))
+                    .pipe(once::run(|In(result): In<Result<(), Interrupted<TestEvent1>>>, mut count: ResMut<Count>| {
+                        if result.is_ok() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn err_if_interrupted() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, interrupt_on::<TestEvent1, _, _>(delay::frames().with(10))
+                    .pipe(once::run(|In(result): In<Result<(), Interrupted<TestEvent1>>>, mut count: ResMut<Count>| {
+                        if result.is_err() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -99,7 +99,9 @@ mod tests {
     use crate::prelude::{Reactor, Map, Pipe};
     use crate::tests::test_app;
     use bevy::app::{Startup, Update};
-    use bevy::prelude::{Commands, In};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
     fn map_num_to_string() {
@@ -112,6 +114,39 @@ mod tests {
         });
     }
 
+    #[test]
+    fn map_output_reaches_next_action() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let output = task.will(Update, once::run(|| 3).map(|num| format!("{num}")))
+                    .await;
+                task.will(Update, once::non_send::insert().with(output)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert_eq!(app.world().non_send_resource::<String>(), "3");
+    }
+
+    #[test]
+    fn map_seed_with_input() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|In(num): In<usize>| num * 2)
+                    .map(|num| num + 1)
+                    .with(3)
+                    .pipe(once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = num;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(7));
+    }
+
     #[test]
     fn overwrite() {
         let mut app = test_app();
@@ -4,24 +4,46 @@ use crate::action::seed::ActionSeed;
 use crate::prelude::RunnerIs;
 use crate::runner::{CancellationHandlers, Output, Runner};
 pub use _no_op::{no_op, no_op_with_generics};
-use bevy::prelude::{IntoSystem, System, SystemIn, SystemInput, World};
+pub use _trigger::{trigger, trigger_targets};
+use bevy::ecs::system::{RegisteredSystemError, SystemId};
+use bevy::prelude::{Commands, In, IntoSystem, System, SystemIn, SystemInput, World};
 
+pub mod counter;
+pub mod entity;
 pub mod event;
+pub mod hierarchy;
+pub mod log;
 pub mod non_send;
 pub mod res;
+pub mod selector;
 pub mod switch;
 #[path = "once/no_op.rs"]
 mod _no_op;
+#[path = "once/trigger.rs"]
+mod _trigger;
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod asset;
 #[cfg(feature = "audio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
 pub mod audio;
+#[cfg(feature = "scene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scene")))]
+pub mod scene;
 #[cfg(feature = "state")]
 #[cfg_attr(docsrs, doc(cfg(feature = "state")))]
 pub mod state;
+#[cfg(feature = "window")]
+#[cfg_attr(docsrs, doc(cfg(feature = "window")))]
+pub mod window;
 
 /// Once run a system.
 ///
 /// The return value will be the system return value.
+/// Commands issued by the system are applied before the next action runs.
+///
+/// Since reactors run on the main thread, the system can also use
+/// [`NonSend`](bevy::prelude::NonSend) and [`NonSendMut`](bevy::prelude::NonSendMut) params.
 ///
 /// ## Examples
 ///
@@ -50,6 +72,132 @@ where
     })
 }
 
+/// Once run a system with a value captured when composing the action and the input of the action.
+///
+/// The system receives `In<(Captured, Input)>`,
+/// so it can combine a value known in advance with the output piped from the previous action.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::run(|| 3)
+///         .pipe(once::run_with("damage", |In((label, amount)): In<(&str, usize)>|{
+///             println!("{label}: {amount}");
+///         }))
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn run_with<Captured, Input, Sys, Out, M>(captured: Captured, system: Sys) -> ActionSeed<Input, Out>
+where
+    Captured: Send + Sync + 'static,
+    Input: 'static,
+    Sys: IntoSystem<In<(Captured, Input)>, Out, M> + 'static + Send + Sync,
+    Out: 'static,
+{
+    ActionSeed::new(move |input, output| OnceRunner {
+        input: Some((captured, input)),
+        output,
+        system: IntoSystem::into_system(system),
+    })
+}
+
+/// Once run `f` with [`Commands`].
+///
+/// The commands are applied right after `f` returns,
+/// so the next chained action observes their effects, even within the same frame.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::commands(|commands| {
+///         commands.spawn(Enemy);
+///     })
+///         .then(wait::query::matches::<With<Enemy>>())
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn commands<F>(f: F) -> ActionSeed
+where
+    F: FnOnce(&mut Commands) + Send + Sync + 'static,
+{
+    let mut f = Some(f);
+    run(move |mut commands: Commands| {
+        if let Some(f) = f.take() {
+            f(&mut commands);
+        }
+    })
+}
+
+/// Once run a system registered with [`World::register_system`] by its [`SystemId`].
+///
+/// Unlike [`once::run`](run), the system state is not initialized every time the action runs,
+/// so it can be reused across reactors.
+/// The input of this action is passed to the system, and the output is the system output,
+/// or [`RegisteredSystemError`] if the system could not be run.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn setup(world: &mut World){
+///     let double = world.register_system(|In(num): In<usize>| num * 2);
+///     world.spawn(Reactor::schedule(move |task| async move{
+///         let doubled = task.will(Update, once::system(double).with(3)).await;
+///         assert_eq!(doubled.ok(), Some(6));
+///     }));
+/// }
+/// ```
+#[inline(always)]
+pub fn system<I, O>(id: SystemId<I, O>) -> ActionSeed<I::Inner<'static>, Result<O, RegisteredSystemError<I, O>>>
+where
+    I: SystemInput + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |input, output| SystemIdRunner {
+        id,
+        input: Some(input),
+        output,
+    })
+}
+
+struct SystemIdRunner<I, O>
+where
+    I: SystemInput + 'static,
+{
+    id: SystemId<I, O>,
+    input: Option<I::Inner<'static>>,
+    output: Output<Result<O, RegisteredSystemError<I, O>>>,
+}
+
+impl<I, O> Runner for SystemIdRunner<I, O>
+where
+    I: SystemInput + 'static,
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        let Some(input) = self.input.take() else {
+            return RunnerIs::Completed;
+        };
+        self.output.set(world.run_system_with_input(self.id, input));
+        RunnerIs::Completed
+    }
+}
+
 struct OnceRunner<Sys>
 where
     Sys: System,
@@ -75,3 +223,69 @@ where
         RunnerIs::Completed
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::{test_app, TestResource};
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::system::RegisteredSystemError;
+    use bevy::prelude::{Commands, In, Res, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn commands_are_applied_before_next_action() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::commands(|commands| {
+                    commands.init_resource::<TestResource>();
+                })
+                    .then(once::run(|resource: Option<Res<TestResource>>, mut count: ResMut<Count>| {
+                        if resource.is_some() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_with_captured_and_piped_input() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| 3)
+                    .pipe(once::run_with(10, |In((captured, piped)): In<(usize, usize)>, mut count: ResMut<Count>| {
+                        count.0 = captured + piped;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(13));
+    }
+
+    #[test]
+    fn run_registered_system() {
+        let mut app = test_app();
+        let double = app.world_mut().register_system(|In(num): In<usize>| num * 2);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::run(|| 3)
+                    .pipe(once::system(double))
+                    .pipe(once::run(|In(result): In<Result<usize, RegisteredSystemError<In<usize>, usize>>>, mut count: ResMut<Count>| {
+                        count.0 = result.unwrap();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(6));
+    }
+}
@@ -0,0 +1,166 @@
+//! [`once::asset`] creates a task that only once run system related to loading assets.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::asset::{Asset, AssetPath, AssetServer, Handle, LoadedFolder, LoadedUntypedAsset};
+use bevy::prelude::{In, Res};
+
+/// Starts loading the asset at the path, and returns its [`Handle`].
+///
+/// This pairs naturally with [`wait::asset::loaded`](crate::prelude::wait::asset::loaded).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, once::asset::load::<Image, _>()
+///         .with("player.png")
+///         .pipe(wait::asset::loaded())
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn load<A, Path>() -> ActionSeed<Path, Handle<A>>
+where
+    A: Asset,
+    Path: Into<AssetPath<'static>> + 'static,
+{
+    once::run(|In(path): In<Path>, asset_server: Res<AssetServer>| {
+        asset_server.load(path.into())
+    })
+}
+
+/// Starts loading all assets in the folder at the path, and returns the [`Handle`] of the [`LoadedFolder`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let folder = task.will(Update, once::asset::load_folder().with("textures")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn load_folder<Path>() -> ActionSeed<Path, Handle<LoadedFolder>>
+where
+    Path: Into<AssetPath<'static>> + 'static,
+{
+    once::run(|In(path): In<Path>, asset_server: Res<AssetServer>| {
+        asset_server.load_folder(path.into())
+    })
+}
+
+/// Starts loading the asset at the path without specifying its type,
+/// and returns the [`Handle`] of the [`LoadedUntypedAsset`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let handle = task.will(Update, once::asset::load_untyped().with("level.scn.ron")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn load_untyped<Path>() -> ActionSeed<Path, Handle<LoadedUntypedAsset>>
+where
+    Path: Into<AssetPath<'static>> + 'static,
+{
+    once::run(|In(path): In<Path>, asset_server: Res<AssetServer>| {
+        asset_server.load_untyped(path.into())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::asset::{Asset, AssetApp, AssetPlugin, AssetServer, Handle, LoadedFolder, LoadedUntypedAsset, UntypedAssetId};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::{Commands, In, Res, ResMut, Resource};
+    use bevy::reflect::TypePath;
+    use std::path::PathBuf;
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset;
+
+    #[derive(Resource, Default)]
+    struct LoadedPath(Option<PathBuf>);
+
+    fn asset_test_app() -> App {
+        let mut app = test_app();
+        app
+            .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<TestAsset>()
+            .init_resource::<LoadedPath>();
+        app
+    }
+
+    fn save_path(id: impl Into<UntypedAssetId>, asset_server: &AssetServer, loaded: &mut LoadedPath) {
+        loaded.0 = asset_server.get_path(id).map(|path| path.path().to_path_buf());
+    }
+
+    fn assert_loaded_path(app: &App, path: &str) {
+        assert_eq!(app.world().resource::<LoadedPath>().0, Some(PathBuf::from(path)));
+    }
+
+    #[test]
+    fn load_asset_at_path() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::asset::load::<TestAsset, _>()
+                    .with("test.asset")
+                    .pipe(once::run(|In(handle): In<Handle<TestAsset>>, asset_server: Res<AssetServer>, mut loaded: ResMut<LoadedPath>| {
+                        save_path(handle.id(), &asset_server, &mut loaded);
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert_loaded_path(&app, "test.asset");
+    }
+
+    #[test]
+    fn load_folder_at_path() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::asset::load_folder()
+                    .with("textures")
+                    .pipe(once::run(|In(handle): In<Handle<LoadedFolder>>, asset_server: Res<AssetServer>, mut loaded: ResMut<LoadedPath>| {
+                        save_path(handle.id(), &asset_server, &mut loaded);
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert_loaded_path(&app, "textures");
+    }
+
+    #[test]
+    fn load_untyped_at_path() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::asset::load_untyped()
+                    .with("level.scn.ron")
+                    .pipe(once::run(|In(handle): In<Handle<LoadedUntypedAsset>>, asset_server: Res<AssetServer>, mut loaded: ResMut<LoadedPath>| {
+                        save_path(handle.id(), &asset_server, &mut loaded);
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert_loaded_path(&app, "level.scn.ron");
+    }
+}
@@ -1,7 +1,7 @@
 //! [`once::audio`] creates a task that only once run system related to audio.
 
-use bevy::asset::{AssetPath, AssetServer};
-use bevy::audio::{AudioPlayer, AudioSource};
+use bevy::asset::{AssetPath, AssetServer, Handle};
+use bevy::audio::{AudioPlayer, AudioSource, PlaybackSettings};
 use bevy::prelude::{Commands, Entity, In, Res};
 use crate::action::once;
 use crate::prelude::ActionSeed;
@@ -34,3 +34,126 @@ where
         },
     )
 }
+
+/// Spawns [`AudioPlayer`] with [`PlaybackSettings`].
+///
+/// The input is a pair of the audio path and the settings,
+/// and the output value is [`Entity`] that controls the audio sink,
+/// which can be passed to [`wait::audio::finished`](crate::prelude::wait::audio::finished).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::audio::play_with()
+///         .with(("<audio_path>", PlaybackSettings::ONCE.with_speed(1.5)))
+///         .pipe(wait::audio::finished())
+///     ).await;
+/// });
+/// ```
+pub fn play_with<Path>() -> ActionSeed<(Path, PlaybackSettings), Entity>
+where
+    Path: Into<AssetPath<'static>> + 'static,
+{
+    once::run(
+        |In((path, settings)): In<(Path, PlaybackSettings)>, mut commands: Commands, asset_server: Res<AssetServer>| {
+            commands
+                .spawn((AudioPlayer::<AudioSource>(asset_server.load(path.into())), settings))
+                .id()
+        },
+    )
+}
+
+/// Spawns [`AudioPlayer`] of the audio handle with [`PlaybackSettings`].
+///
+/// The input is a pair of the audio handle and the settings,
+/// and the output value is [`Entity`] that controls the audio sink.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let handle = task.will(Update, once::run(|asset_server: Res<AssetServer>| {
+///         asset_server.load::<AudioSource>("<audio_path>")
+///     })).await;
+///     task.will(Update, once::audio::play_handle()
+///         .with((handle, PlaybackSettings::LOOP))
+///     ).await;
+/// });
+/// ```
+pub fn play_handle() -> ActionSeed<(Handle<AudioSource>, PlaybackSettings), Entity> {
+    once::run(
+        |In((handle, settings)): In<(Handle<AudioSource>, PlaybackSettings)>, mut commands: Commands| {
+            commands
+                .spawn((AudioPlayer(handle), settings))
+                .id()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::Reactor;
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::asset::{AssetApp, AssetPlugin, Handle};
+    use bevy::audio::{AudioPlayer, AudioSource, PlaybackSettings};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::Commands;
+
+    fn audio_test_app() -> App {
+        let mut app = test_app();
+        app
+            .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<AudioSource>();
+        app
+    }
+
+    fn spawned_player(app: &mut App) -> (Handle<AudioSource>, f32) {
+        let (player, settings) = app
+            .world_mut()
+            .query::<(&AudioPlayer<AudioSource>, &PlaybackSettings)>()
+            .single(app.world());
+        (player.0.clone(), settings.speed)
+    }
+
+    #[test]
+    fn play_with_settings() {
+        let mut app = audio_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::audio::play_with()
+                    .with(("test.ogg", PlaybackSettings::ONCE.with_speed(1.5))),
+                ).await;
+            }));
+        });
+        app.update();
+
+        let (_, speed) = spawned_player(&mut app);
+        assert_eq!(speed, 1.5);
+    }
+
+    #[test]
+    fn play_handle_with_settings() {
+        let mut app = audio_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::audio::play_handle()
+                    .with((Handle::default(), PlaybackSettings::LOOP.with_speed(0.5))),
+                ).await;
+            }));
+        });
+        app.update();
+
+        let (handle, speed) = spawned_player(&mut app);
+        assert_eq!(handle, Handle::default());
+        assert_eq!(speed, 0.5);
+    }
+}
@@ -0,0 +1,82 @@
+//! [`once::counter`] creates a task that only once run system related to [`Counter`].
+
+use bevy::prelude::World;
+use crate::action::once;
+use crate::action::seed::ActionSeed;
+use crate::action::switch::Counter;
+
+/// Increments [`Counter`], inserting it with 0 first if it doesn't exist yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Workers;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::counter::increment::<Workers>()).await;
+/// });
+/// ```
+#[inline]
+pub fn increment<M>() -> ActionSeed
+    where M: Send + Sync + 'static
+{
+    once::run(|world: &mut World| {
+        world.get_resource_or_insert_with(Counter::<M>::default).increment();
+    })
+}
+
+/// Decrements [`Counter`], saturating at 0, inserting it with 0 first if it doesn't exist yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Workers;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::counter::decrement::<Workers>()).await;
+/// });
+/// ```
+#[inline]
+pub fn decrement<M>() -> ActionSeed
+    where M: Send + Sync + 'static
+{
+    once::run(|world: &mut World| {
+        world.get_resource_or_insert_with(Counter::<M>::default).decrement();
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::Startup;
+    use bevy::prelude::{Commands, Update};
+    use crate::action::once;
+    use crate::prelude::{Counter, Then};
+    use crate::reactor::Reactor;
+    use crate::tests::test_app;
+
+    struct T;
+
+    #[test]
+    fn once_counter_increment_and_decrement() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::counter::increment::<T>()
+                    .then(once::counter::increment::<T>()),
+                ).await;
+                task.will(Update, once::counter::decrement::<T>()).await;
+            }));
+        });
+
+        app.update();
+        assert_eq!(app.world().resource::<Counter<T>>().get(), 2);
+        app.update();
+        assert_eq!(app.world().resource::<Counter<T>>().get(), 1);
+    }
+}
@@ -0,0 +1,306 @@
+//! [`once::entity`] creates a task that only once run system related to [`Entity`].
+
+use crate::action::once;
+use crate::action::seed::ActionSeed;
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Bundle, Commands, Entity, In, World};
+
+/// Once spawn an entity with the bundle, and return the spawned entity.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// Reactor::schedule(|task| async move{
+///     let enemy: Entity = task.will(Update, once::entity::spawn().with(Enemy)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn spawn<B>() -> ActionSeed<B, Entity>
+where
+    B: Bundle,
+{
+    once::run(|In(bundle): In<B>, mut commands: Commands| {
+        commands.spawn(bundle).id()
+    })
+}
+
+/// Once insert the bundle into the entity, and return the entity.
+///
+/// Since the input is the target entity, it can be chained after actions that output an entity,
+/// such as [`once::entity::spawn`](spawn).
+/// Nothing is inserted if the entity doesn't exist.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::entity::spawn()
+///         .with(Enemy)
+///         .pipe(once::entity::insert(Name::new("enemy")))
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn insert<B>(bundle: B) -> ActionSeed<Entity, Entity>
+where
+    B: Bundle,
+{
+    let mut bundle = Some(bundle);
+    once::run(move |In(entity): In<Entity>, world: &mut World| {
+        if let Some(bundle) = bundle.take() {
+            if world.entities().contains(entity) {
+                world.entity_mut(entity).insert(bundle);
+            }
+        }
+        entity
+    })
+}
+
+/// Once remove the bundle `B` from the entity, and return the entity.
+///
+/// Since the input is the target entity, it can be chained after actions that output an entity,
+/// such as [`wait::component::added`](crate::prelude::wait::component::added).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Stunned;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::component::added::<Stunned>()
+///         .pipe(once::entity::remove::<Stunned>())
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn remove<B>() -> ActionSeed<Entity, Entity>
+where
+    B: Bundle,
+{
+    once::run(|In(entity): In<Entity>, world: &mut World| {
+        if world.entities().contains(entity) {
+            world.entity_mut(entity).remove::<B>();
+        }
+        entity
+    })
+}
+
+/// Once despawn the entity.
+///
+/// The output is true if the entity existed.
+/// Its children are not despawned; use [`despawn_recursive`] to despawn them together.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::entity::spawn()
+///         .with(Name::new("enemy"))
+///         .pipe(once::entity::despawn())
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn() -> ActionSeed<Entity, bool> {
+    once::run(|In(entity): In<Entity>, world: &mut World| {
+        let exists = world.entities().contains(entity);
+        if exists {
+            world.despawn(entity);
+        }
+        exists
+    })
+}
+
+/// Once despawn the entity and all its descendants.
+///
+/// The output is true if the entity existed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let stage = task.will(Update, once::entity::spawn().with(Name::new("stage"))).await;
+///     task.will(Update, once::entity::despawn_recursive().with(stage)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn_recursive() -> ActionSeed<Entity, bool> {
+    once::run(|In(entity): In<Entity>, world: &mut World| {
+        let exists = world.entities().contains(entity);
+        if exists {
+            world.entity_mut(entity).despawn_recursive();
+        }
+        exists
+    })
+}
+
+/// Once despawn all descendants of the entity, keeping the entity itself.
+///
+/// The output is true if the entity existed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let stage = task.will(Update, once::entity::spawn().with(Name::new("stage"))).await;
+///     task.will(Update, once::entity::despawn_descendants().with(stage)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn_descendants() -> ActionSeed<Entity, bool> {
+    once::run(|In(entity): In<Entity>, world: &mut World| {
+        let exists = world.entities().contains(entity);
+        if exists {
+            world.entity_mut(entity).despawn_descendants();
+        }
+        exists
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::hierarchy::BuildChildren;
+    use bevy::prelude::{Commands, Component, Entity, In, Query, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component)]
+    struct TestComponent;
+
+    #[test]
+    fn spawn_entity() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::entity::spawn()
+                    .with(TestComponent)
+                    .pipe(once::run(|In(entity): In<Entity>, components: Query<Entity, With<TestComponent>>, mut count: ResMut<Count>| {
+                        if components.get(entity).is_ok() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn despawn_entity() {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn(TestComponent).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::entity::despawn()
+                    .with(entity)
+                    .pipe(once::run(|In(existed): In<bool>, mut count: ResMut<Count>| {
+                        if existed {
+                            count.increment();
+                        }
+                    }))
+                    .then(once::entity::despawn().with(entity))
+                    .pipe(once::run(|In(existed): In<bool>, mut count: ResMut<Count>| {
+                        if existed {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert!(!app.world().entities().contains(entity));
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn despawn_entity_recursive() {
+        let mut app = test_app();
+        let child = app.world_mut().spawn(TestComponent).id();
+        let parent = app.world_mut().spawn_empty().add_child(child).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::entity::despawn_recursive().with(parent)).await;
+            }));
+        });
+        app.update();
+        assert!(!app.world().entities().contains(parent));
+        assert!(!app.world().entities().contains(child));
+    }
+
+    #[test]
+    fn despawn_entity_descendants() {
+        let mut app = test_app();
+        let child = app.world_mut().spawn(TestComponent).id();
+        let parent = app.world_mut().spawn_empty().add_child(child).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::entity::despawn_descendants().with(parent)).await;
+            }));
+        });
+        app.update();
+        assert!(app.world().entities().contains(parent));
+        assert!(!app.world().entities().contains(child));
+    }
+
+    #[test]
+    fn insert_and_remove_component() {
+        #[derive(Component)]
+        struct Marker;
+
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let entity = task.will(Update, once::entity::spawn()
+                    .with(TestComponent)
+                    .pipe(once::entity::insert(Marker)),
+                ).await;
+                task.will(Update, once::run(move |markers: Query<Entity, With<Marker>>, mut count: ResMut<Count>| {
+                    if markers.get(entity).is_ok() {
+                        count.increment();
+                    }
+                })).await;
+                task.will(Update, once::entity::remove::<Marker>().with(entity)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        let markers = app
+            .world_mut()
+            .query_filtered::<Entity, With<Marker>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(markers, 0);
+    }
+}
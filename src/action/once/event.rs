@@ -27,6 +27,32 @@ where
     })
 }
 
+/// Once send multiple events in the order of the passed iterator.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event)]
+/// struct Spawn(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::event::send_batch().with((0..3).map(Spawn))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn send_batch<E, Batch>() -> ActionSeed<Batch, ()>
+where
+    E: Event,
+    Batch: IntoIterator<Item=E> + 'static,
+{
+    once::run(|In(batch): In<Batch>, mut w: EventWriter<E>| {
+        w.send_batch(batch);
+    })
+}
+
 /// Once send an event using [`Default`] trait.
 ///
 /// ## Examples
@@ -73,7 +99,8 @@ mod tests {
     use crate::prelude::Reactor;
     use crate::tests::{came_event, test_app};
     use bevy::app::{AppExit, First, Startup};
-    use bevy::prelude::Commands;
+    use bevy::prelude::{Commands, Events};
+    use bevy_test_helper::event::TestEvent1;
 
     #[test]
     fn send_event() {
@@ -100,4 +127,17 @@ mod tests {
         app.update();
         assert!(came_event::<AppExit>(&mut app));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn send_batch_events() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, once::event::send_batch().with(vec![TestEvent1, TestEvent1, TestEvent1])).await;
+            }));
+        });
+
+        app.update();
+        assert_eq!(app.world().resource::<Events<TestEvent1>>().len(), 3);
+    }
+}
@@ -0,0 +1,190 @@
+//! [`once::hierarchy`] creates a task that only once run system related to the entity hierarchy.
+
+use crate::action::once;
+use crate::action::seed::ActionSeed;
+use bevy::hierarchy::BuildChildren;
+use bevy::prelude::{Entity, In, World};
+
+/// Once set the parent of the child entity, and return the child.
+///
+/// The input is `(child, parent)`.
+/// Nothing happens if either entity doesn't exist or they are the same entity.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let table = task.will(Update, once::entity::spawn().with(Name::new("table"))).await;
+///     let cup = task.will(Update, once::entity::spawn().with(Name::new("cup"))).await;
+///     task.will(Update, once::hierarchy::set_parent().with((cup, table))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn set_parent() -> ActionSeed<(Entity, Entity), Entity> {
+    once::run(|In((child, parent)): In<(Entity, Entity)>, world: &mut World| {
+        set_parent_if_exists(world, child, parent);
+        child
+    })
+}
+
+/// Once set `parent` as the parent of the child entity passed as the input, and return the child.
+///
+/// Since the input is the child entity, it can be chained after actions that output an entity,
+/// such as [`once::entity::spawn`](crate::prelude::once::entity::spawn).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let table = task.will(Update, once::entity::spawn().with(Name::new("table"))).await;
+///     task.will(Update, once::entity::spawn()
+///         .with(Name::new("cup"))
+///         .pipe(once::hierarchy::set_parent_to(table))
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn set_parent_to(parent: Entity) -> ActionSeed<Entity, Entity> {
+    once::run(move |In(child): In<Entity>, world: &mut World| {
+        set_parent_if_exists(world, child, parent);
+        child
+    })
+}
+
+fn set_parent_if_exists(world: &mut World, child: Entity, parent: Entity) {
+    if child != parent && world.entities().contains(child) && world.entities().contains(parent) {
+        world.entity_mut(child).set_parent(parent);
+    }
+}
+
+/// Once remove the parent of the child entity, and return the child.
+///
+/// The child itself is kept in the world.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let table = task.will(Update, once::entity::spawn().with(Name::new("table"))).await;
+///     let cup = task.will(Update, once::entity::spawn()
+///         .with(Name::new("cup"))
+///         .pipe(once::hierarchy::set_parent_to(table))
+///     ).await;
+///     task.will(Update, once::hierarchy::remove_parent().with(cup)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn remove_parent() -> ActionSeed<Entity, Entity> {
+    once::run(|In(child): In<Entity>, world: &mut World| {
+        if world.entities().contains(child) {
+            world.entity_mut(child).remove_parent();
+        }
+        child
+    })
+}
+
+/// Once despawn all children of the parent entity recursively, keeping the parent itself.
+///
+/// The output is true if the parent existed.
+///
+/// This is the same action as [`once::entity::despawn_descendants`](crate::prelude::once::entity::despawn_descendants).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let menu = task.will(Update, once::entity::spawn().with(Name::new("menu"))).await;
+///     task.will(Update, once::hierarchy::despawn_children().with(menu)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn_children() -> ActionSeed<Entity, bool> {
+    once::entity::despawn_descendants()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::hierarchy::{BuildChildren, Children, Parent};
+    use bevy::prelude::{Commands, Component, Entity, With};
+
+    #[derive(Component)]
+    struct ParentMarker;
+
+    #[derive(Component)]
+    struct ChildMarker;
+
+    fn find<C: Component>(app: &mut bevy::app::App) -> Entity {
+        app.world_mut()
+            .query_filtered::<Entity, With<C>>()
+            .single(app.world())
+    }
+
+    #[test]
+    fn set_parent_to_piped_child() {
+        let mut app = test_app();
+        let parent = app.world_mut().spawn(ParentMarker).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::entity::spawn()
+                    .with(ChildMarker)
+                    .pipe(once::hierarchy::set_parent_to(parent)),
+                ).await;
+            }));
+        });
+        app.update();
+        let child = find::<ChildMarker>(&mut app);
+        assert_eq!(app.world().get::<Parent>(child).map(|p| p.get()), Some(parent));
+    }
+
+    #[test]
+    fn remove_parent() {
+        let mut app = test_app();
+        let parent = app.world_mut().spawn(ParentMarker).id();
+        let child = app.world_mut().spawn(ChildMarker).id();
+        app.world_mut().entity_mut(parent).add_child(child);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::hierarchy::remove_parent().with(child)).await;
+            }));
+        });
+        app.update();
+        assert!(app.world().get::<Parent>(child).is_none());
+        assert!(app.world().entities().contains(child));
+    }
+
+    #[test]
+    fn despawn_children() {
+        let mut app = test_app();
+        let parent = app.world_mut().spawn(ParentMarker).id();
+        let child = app.world_mut().spawn(ChildMarker).id();
+        let grandchild = app.world_mut().spawn_empty().id();
+        app.world_mut().entity_mut(parent).add_child(child);
+        app.world_mut().entity_mut(child).add_child(grandchild);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::hierarchy::despawn_children().with(parent)).await;
+            }));
+        });
+        app.update();
+        assert!(app.world().entities().contains(parent));
+        assert!(app.world().get::<Children>(parent).is_none());
+        assert!(!app.world().entities().contains(child));
+        assert!(!app.world().entities().contains(grandchild));
+    }
+}
@@ -0,0 +1,131 @@
+//! [`once::log`] creates a task that only once logs a message tagged with the reactor entity.
+//!
+//! The message is logged with a `reactor` field holding the entity of the reactor running the action,
+//! so the logs of multiple reactors running the same sequence can be told apart,
+//! and a `step` field holding the number of the `task.will` that runs the action, starting from 1.
+//! Actions chained within the same `task.will` share its step.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use crate::runner::RunningReactor;
+use bevy::prelude::{Entity, Res};
+
+/// Once log the message at the trace level.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::log::trace("start")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn trace(message: impl Into<String>) -> ActionSeed {
+    log(message.into(), |reactor, step, message| bevy::log::trace!(reactor = ?reactor, step, "{message}"))
+}
+
+/// Once log the message at the debug level.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::log::debug("start")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn debug(message: impl Into<String>) -> ActionSeed {
+    log(message.into(), |reactor, step, message| bevy::log::debug!(reactor = ?reactor, step, "{message}"))
+}
+
+/// Once log the message at the info level.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::log::info("open the door")
+///         .then(delay::frames().with(30))
+///         .then(once::log::info("enter the room"))
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn info(message: impl Into<String>) -> ActionSeed {
+    log(message.into(), |reactor, step, message| bevy::log::info!(reactor = ?reactor, step, "{message}"))
+}
+
+/// Once log the message at the warn level.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::log::warn("the target is not found")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn warn(message: impl Into<String>) -> ActionSeed {
+    log(message.into(), |reactor, step, message| bevy::log::warn!(reactor = ?reactor, step, "{message}"))
+}
+
+/// Once log the message at the error level.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::log::error("failed to load the stage")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn error(message: impl Into<String>) -> ActionSeed {
+    log(message.into(), |reactor, step, message| bevy::log::error!(reactor = ?reactor, step, "{message}"))
+}
+
+fn log(message: String, f: fn(Option<Entity>, Option<usize>, &str)) -> ActionSeed {
+    once::run(move |reactor: Option<Res<RunningReactor>>| {
+        f(reactor.as_ref().map(|reactor| reactor.0), reactor.as_ref().map(|reactor| reactor.1), &message);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn log_and_run_next_action() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::log::info("increment")
+                    .then(increment_count())
+                    .then(once::log::warn(format!("count: {}", 1))),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
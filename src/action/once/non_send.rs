@@ -53,7 +53,9 @@ where
     })
 }
 
-/// Once remove a non-send resource.
+/// Once remove a non-send resource and return it.
+///
+/// The output is `None` if the resource did not exist.
 ///
 /// ## Examples
 ///
@@ -64,26 +66,38 @@ where
 /// struct Res;
 ///
 /// Reactor::schedule(|task| async move{
-///     task.will(Update, once::non_send::remove::<Res>()).await;
+///     let res: Option<Res> = task.will(Update, once::non_send::remove::<Res>()).await;
 /// });
 /// ```
 #[inline(always)]
-pub fn remove<R>() -> ActionSeed
+pub fn remove<R>() -> ActionSeed<(), Option<R>>
 where
     R: 'static,
 {
     once::run(|world: &mut World| {
-        world.remove_non_send_resource::<R>();
+        world.remove_non_send_resource::<R>()
     })
 }
 
+/// An alias of [`remove`].
+#[inline(always)]
+pub fn take<R>() -> ActionSeed<(), Option<R>>
+where
+    R: 'static,
+{
+    remove::<R>()
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::action::once;
     use crate::action::once::non_send;
-    use crate::prelude::Reactor;
+    use crate::prelude::{Pipe, Reactor};
     use crate::tests::{test_app, TestResource};
     use bevy::app::{AppExit, First, PostUpdate, PreUpdate, Startup, Update};
-    use bevy::prelude::Commands;
+    use bevy::prelude::{Commands, In, NonSendMut, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
     fn init_non_send_resource() {
@@ -135,6 +149,48 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn take_non_send_resource() {
+        let mut app = test_app();
+        app.world_mut().init_non_send_resource::<TestResource>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, non_send::take::<TestResource>()
+                    .pipe(once::run(|In(taken): In<Option<TestResource>>, mut count: ResMut<Count>| {
+                        if taken.is_some() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+
+        app.update();
+        assert!(app
+            .world()
+            .get_non_send_resource::<TestResource>()
+            .is_none());
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_system_with_non_send_params() {
+        struct NonSendCount(usize);
+
+        let mut app = test_app();
+        app.world_mut().insert_non_send_resource(NonSendCount(0));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|mut count: NonSendMut<NonSendCount>| {
+                    count.0 += 1;
+                })).await;
+            }));
+        });
+
+        app.update();
+        assert_eq!(app.world().non_send_resource::<NonSendCount>().0, 1);
+    }
+
     #[test]
     fn success_run_all_schedule_labels() {
         let mut app = test_app();
@@ -1,11 +1,13 @@
 //! [`once::res`] creates a task that only once run system related to [`Resource`].
 
-use bevy::prelude::{Commands, In, Resource};
+use bevy::prelude::{Commands, FromWorld, In, Resource, World};
 use crate::action::once;
 use crate::action::seed::ActionSeed;
 
 /// Once init a resource.
 ///
+/// The resource is created with [`FromWorld`] when the action runs, unless it already exists.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -21,7 +23,7 @@ use crate::action::seed::ActionSeed;
 #[inline(always)]
 pub fn init<R>() -> ActionSeed
 where
-    R: Resource + Default + 'static,
+    R: Resource + FromWorld + 'static,
 {
     once::run(|mut commands: Commands| {
         commands.init_resource::<R>();
@@ -52,7 +54,10 @@ where
     })
 }
 
-/// Once remove a resource.
+/// Once insert a resource created by `f`.
+///
+/// Unlike [`insert`], the resource is not created until the action runs,
+/// so no work is wasted if the reactor is canceled before that.
 ///
 /// ## Examples
 ///
@@ -60,30 +65,71 @@ where
 /// use bevy::prelude::*;
 /// use bevy_flurx::prelude::*;
 /// #[derive(Resource)]
-/// struct Res;
+/// struct Res(Vec<u8>);
 ///
 /// Reactor::schedule(|task| async move{
-///     task.will(Update, once::res::remove::<Res>()).await;
+///     task.will(Update, once::res::insert_with(|| Res(vec![0; 1024]))).await;
 /// });
 /// ```
 #[inline(always)]
-pub fn remove<R>() -> ActionSeed
+pub fn insert_with<R, F>(f: F) -> ActionSeed
 where
     R: Resource + 'static,
+    F: FnOnce() -> R + Send + Sync + 'static,
 {
-    once::run(|mut commands: Commands| {
-        commands.remove_resource::<R>();
+    let mut f = Some(f);
+    once::run(move |mut commands: Commands| {
+        if let Some(f) = f.take() {
+            commands.insert_resource(f());
+        }
     })
 }
 
+/// Once remove a resource and return it.
+///
+/// The output is `None` if the resource did not exist.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// #[derive(Resource)]
+/// struct Score(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let score: Option<Score> = task.will(Update, once::res::remove::<Score>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn remove<R>() -> ActionSeed<(), Option<R>>
+where
+    R: Resource + 'static,
+{
+    once::run(|world: &mut World| {
+        world.remove_resource::<R>()
+    })
+}
+
+/// An alias of [`remove`].
+#[inline(always)]
+pub fn take<R>() -> ActionSeed<(), Option<R>>
+where
+    R: Resource + 'static,
+{
+    remove::<R>()
+}
 
 #[cfg(test)]
 mod tests {
+    use crate::action::once;
     use crate::action::once::res;
-    use crate::prelude::Reactor;
+    use crate::prelude::{Pipe, Reactor};
     use crate::tests::{test_app, TestResource};
     use bevy::app::{First, Startup};
-    use bevy::prelude::Commands;
+    use bevy::prelude::{Commands, FromWorld, In, ResMut, Resource, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
     fn init_resource() {
@@ -124,4 +170,61 @@ mod tests {
         app.update();
         assert!(app.world().get_resource::<TestResource>().is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn insert_resource_with_closure() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, res::insert_with(|| TestResource)).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.world().get_resource::<TestResource>().is_some());
+    }
+
+    #[test]
+    fn init_resource_from_world() {
+        #[derive(Resource)]
+        struct FromWorldResource(bool);
+
+        impl FromWorld for FromWorldResource {
+            fn from_world(world: &mut World) -> Self {
+                Self(world.contains_resource::<TestResource>())
+            }
+        }
+
+        let mut app = test_app();
+        app.init_resource::<TestResource>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(First, res::init::<FromWorldResource>()).await;
+                }));
+            });
+
+        app.update();
+        assert!(app.world().resource::<FromWorldResource>().0);
+    }
+
+    #[test]
+    fn remove_resource_and_output_it() {
+        let mut app = test_app();
+        app.init_resource::<TestResource>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(First, res::remove::<TestResource>()
+                        .pipe(once::run(|In(taken): In<Option<TestResource>>, mut count: ResMut<Count>| {
+                            if taken == Some(TestResource) {
+                                count.increment();
+                            }
+                        })),
+                    ).await;
+                }));
+            });
+
+        app.update();
+        assert!(app.world().get_resource::<TestResource>().is_none());
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,142 @@
+//! [`once::scene`] creates a task that only once run system related to scenes.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::asset::Handle;
+use bevy::prelude::{Entity, In, ResMut};
+use bevy::scene::{InstanceId, Scene, SceneSpawner};
+
+/// Spawns the scene, and returns the [`InstanceId`] of the spawned instance.
+///
+/// The instance is spawned once the scene is loaded,
+/// so wait for it with [`wait::scene::ready`](crate::prelude::wait::scene::ready) before using its entities.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let handle = task.will(Update, once::run(|asset_server: Res<AssetServer>| {
+///         asset_server.load(GltfAssetLabel::Scene(0).from_asset("level.glb"))
+///     })).await;
+///     let roots = task.will(Update, once::scene::spawn()
+///         .with(handle)
+///         .pipe(wait::scene::ready())
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn spawn() -> ActionSeed<Handle<Scene>, InstanceId> {
+    once::run(|In(handle): In<Handle<Scene>>, mut spawner: ResMut<SceneSpawner>| {
+        spawner.spawn(handle)
+    })
+}
+
+/// Spawns the scene as a child of the parent entity, and returns the [`InstanceId`] of the spawned instance.
+///
+/// The input is a pair of the scene handle and the parent entity.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let parent = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     let handle = task.will(Update, once::run(|asset_server: Res<AssetServer>| {
+///         asset_server.load(GltfAssetLabel::Scene(0).from_asset("prop.glb"))
+///     })).await;
+///     task.will(Update, once::scene::spawn_as_child()
+///         .with((handle, parent))
+///         .pipe(wait::scene::ready())
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn spawn_as_child() -> ActionSeed<(Handle<Scene>, Entity), InstanceId> {
+    once::run(|In((handle, parent)): In<(Handle<Scene>, Entity)>, mut spawner: ResMut<SceneSpawner>| {
+        spawner.spawn_as_child(handle, parent)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::asset::{AssetPlugin, Assets, Handle};
+    use bevy::hierarchy::{Children, Parent};
+    use bevy::prelude::{Commands, Component, Entity, In, ReflectComponent, ResMut, With, World};
+    use bevy::reflect::Reflect;
+    use bevy::scene::{Scene, ScenePlugin};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Marker;
+
+    fn scene_test_app() -> (App, Handle<Scene>) {
+        let mut app = test_app();
+        app
+            .add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<Marker>()
+            .register_type::<Parent>()
+            .register_type::<Children>();
+        let mut scene_world = World::new();
+        scene_world.spawn(Marker);
+        let scene = app.world_mut().resource_mut::<Assets<Scene>>().add(Scene::new(scene_world));
+        (app, scene)
+    }
+
+    #[test]
+    fn spawn_scene() {
+        let (mut app, scene) = scene_test_app();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let scene = scene.clone();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::scene::spawn()
+                    .with(scene)
+                    .pipe(wait::scene::ready())
+                    .pipe(once::run(|In(roots): In<Vec<Entity>>, mut count: ResMut<Count>| {
+                        count.0 = roots.len();
+                    })),
+                ).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+        assert_eq!(app.world_mut().query_filtered::<(), With<Marker>>().iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn spawn_scene_as_child() {
+        let (mut app, scene) = scene_test_app();
+        let parent = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let scene = scene.clone();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::scene::spawn_as_child()
+                    .with((scene, parent))
+                    .pipe(wait::scene::ready()),
+                ).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        let marker_parent = app
+            .world_mut()
+            .query_filtered::<&Parent, With<Marker>>()
+            .single(app.world());
+        assert_eq!(marker_parent.get(), parent);
+    }
+}
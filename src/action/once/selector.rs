@@ -0,0 +1,80 @@
+//! [`once::selector`] creates a task that only once run system related to [`Selector`].
+
+use bevy::prelude::{In, World};
+use crate::action::once;
+use crate::action::seed::ActionSeed;
+use crate::action::selector::Selector;
+
+/// Selects the state of [`Selector`] passed as the input.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Door;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum DoorState{
+///     Opened,
+///     Closed,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::selector::set::<Door, _>().with(DoorState::Opened)).await;
+/// });
+/// ```
+#[inline]
+pub fn set<M, S>() -> ActionSeed<S>
+    where
+        M: Send + Sync + 'static,
+        S: Send + Sync + 'static
+{
+    once::run(|In(state): In<S>, world: &mut World| {
+        if let Some(mut selector) = world.get_resource_mut::<Selector<M, S>>() {
+            selector.set(state);
+        } else {
+            world.insert_resource(Selector::<M, S>::new(state));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::Startup;
+    use bevy::prelude::{Commands, IntoSystemConfigs, ResMut, Update};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use crate::action::once;
+    use crate::prelude::selector_is;
+    use crate::reactor::Reactor;
+    use crate::tests::test_app;
+
+    struct T;
+
+    #[derive(Clone, PartialEq)]
+    enum S {
+        A,
+        B,
+    }
+
+    #[test]
+    fn once_selector_set() {
+        let mut app = test_app();
+        app
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, once::selector::set::<T, _>().with(S::A)).await;
+                    task.will(Update, once::selector::set::<T, _>().with(S::B)).await;
+                }));
+            })
+            .add_systems(Update, (|mut b: ResMut<Bool>| {
+                **b = true;
+            }).run_if(selector_is::<T, _>(S::B)));
+
+        app.update();
+        assert!(app.is_bool_false());
+        app.update();
+        assert!(app.is_bool_true());
+    }
+}
@@ -8,6 +8,9 @@ use crate::prelude::ActionSeed;
 
 /// Once set a next state.
 ///
+/// The state is the input of this action, so it can be passed with [`ActionSeed::with`]
+/// or piped from the output of the previous action.
+///
 /// ## Examples
 ///
 /// ```
@@ -23,6 +26,9 @@ use crate::prelude::ActionSeed;
 ///
 /// Reactor::schedule(|task| async move{
 ///     task.will(Update, once::state::set().with(S::S2)).await;
+///
+///     // The next state can also be computed by the previous action.
+///     task.will(Update, once::run(|| S::S1).pipe(once::state::set())).await;
 /// });
 /// ```
 pub fn set<S>() -> ActionSeed<S>
@@ -1,10 +1,11 @@
 //! [`once::switch`] creates a task that only once run system related to [`Switch`].
 
 
-use bevy::prelude::World;
+use bevy::prelude::{Entity, In, World};
 use crate::action::once;
 use crate::action::seed::ActionSeed;
-use crate::action::switch::Switch;
+use crate::action::switch::{register_switch_timer, Switch, SwitchComponent, SwitchWorldExtension};
+use std::time::Duration;
 
 
 /// Turns [`Switch`] on.
@@ -53,16 +54,221 @@ pub fn off<M>() -> ActionSeed
     })
 }
 
+/// Toggles [`Switch`], turning it on if it is off or doesn't exist yet, and off otherwise.
+/// 
+/// ## Examples
+/// 
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct DebugOverlay;
+///
+/// Reactor::schedule(|task| async move{
+///     loop {
+///         task.will(Update, wait::input::just_pressed().with(KeyCode::F3)).await;
+///         task.will(Update, once::switch::toggle::<DebugOverlay>()).await;
+///     }
+/// });
+/// ```
+#[inline]
+pub fn toggle<M>() -> ActionSeed
+    where M: Send + Sync + 'static
+{
+    once::run(|world: &mut World| {
+        let turn_on = !world
+            .get_resource::<Switch<M>>()
+            .is_some_and(|switch| switch.is_on());
+        Switch::<M>::setup(world, turn_on);
+    })
+}
+
+/// Turns [`Switch<M, T>`] on with the payload passed as the input.
+///
+/// The payload can be read by the system reacting to the switch via [`Switch::payload`].
+/// 
+/// ## Examples
+/// 
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct LoadLevel;
+///
+/// fn load_level(mut switch: ResMut<Switch<LoadLevel, usize>>){
+///     if let Some(level) = switch.take_payload() {
+///         println!("load level {level}");
+///         switch.off();
+///     }
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::switch::on_with::<LoadLevel, usize>().with(2)).await;
+/// });
+/// ```
+#[inline]
+pub fn on_with<M, T>() -> ActionSeed<T>
+    where
+        M: Send + Sync + 'static,
+        T: Send + Sync + 'static
+{
+    once::run(|In(payload): In<T>, world: &mut World| {
+        world
+            .get_resource_or_insert_with(Switch::<M, T>::default)
+            .set_on_with(payload);
+    })
+}
+
+/// Turns [`Switch`] on, and turns it off automatically after the duration passed as the input has elapsed.
+///
+/// This action completes immediately, and the switch is turned off in `First` once the duration has elapsed.
+/// The switch is registered for the expiration on the first run,
+/// as [`SwitchExtension::add_switch_timer`](crate::prelude::SwitchExtension::add_switch_timer) does.
+/// 
+/// ## Examples
+/// 
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Invincible;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::switch::on_for_duration::<Invincible>().with(Duration::from_secs(2))).await;
+/// });
+/// ```
+#[inline]
+pub fn on_for_duration<M>() -> ActionSeed<Duration>
+    where M: Send + Sync + 'static
+{
+    once::run(|In(duration): In<Duration>, world: &mut World| {
+        register_switch_timer::<M>(world);
+        Switch::<M>::setup(world, true).on_for(duration);
+    })
+}
+
+/// Turns [`SwitchComponent`] of the entity passed as the input on, and return the entity.
+///
+/// The component is inserted if the entity doesn't have it yet.
+/// Nothing happens if the entity doesn't exist.
+/// 
+/// ## Examples
+/// 
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Open;
+///
+/// Reactor::schedule(|task| async move{
+///     let door = task.will(Update, once::entity::spawn().with(Name::new("door"))).await;
+///     task.will(Update, once::switch::on_for::<Open>().with(door)).await;
+/// });
+/// ```
+#[inline]
+pub fn on_for<M>() -> ActionSeed<Entity, Entity>
+    where M: Send + Sync + 'static
+{
+    once::run(|In(entity): In<Entity>, world: &mut World| {
+        set_component::<M>(world, entity, true);
+        entity
+    })
+}
+
+/// Turns [`SwitchComponent`] of the entity passed as the input off, and return the entity.
+///
+/// The component is inserted if the entity doesn't have it yet.
+/// Nothing happens if the entity doesn't exist.
+/// 
+/// ## Examples
+/// 
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Open;
+///
+/// Reactor::schedule(|task| async move{
+///     let door = task.will(Update, once::entity::spawn().with(Name::new("door"))).await;
+///     task.will(Update, once::switch::off_for::<Open>().with(door)).await;
+/// });
+/// ```
+#[inline]
+pub fn off_for<M>() -> ActionSeed<Entity, Entity>
+    where M: Send + Sync + 'static
+{
+    once::run(|In(entity): In<Entity>, world: &mut World| {
+        set_component::<M>(world, entity, false);
+        entity
+    })
+}
+
+/// Turns on all switches registered under `group` in [`SwitchGroups`](crate::prelude::SwitchGroups).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::switch::group_on("combat")).await;
+/// });
+/// ```
+#[inline]
+pub fn group_on(group: impl Into<String>) -> ActionSeed {
+    let group = group.into();
+    once::run(move |world: &mut World| {
+        world.switch_group_on(&group);
+    })
+}
+
+/// Turns off all switches registered under `group` in [`SwitchGroups`](crate::prelude::SwitchGroups).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::switch::group_off("combat")).await;
+/// });
+/// ```
+#[inline]
+pub fn group_off(group: impl Into<String>) -> ActionSeed {
+    let group = group.into();
+    once::run(move |world: &mut World| {
+        world.switch_group_off(&group);
+    })
+}
+
+fn set_component<M>(world: &mut World, entity: Entity, turn_on: bool)
+    where M: Send + Sync + 'static
+{
+    if !world.entities().contains(entity) {
+        return;
+    }
+    let mut entity_mut = world.entity_mut(entity);
+    if let Some(mut switch) = entity_mut.get_mut::<SwitchComponent<M>>() {
+        switch.set(turn_on);
+    } else {
+        entity_mut.insert(SwitchComponent::<M>::new(turn_on));
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use bevy::app::Startup;
+    use bevy::app::{First, Startup};
     use bevy::prelude::{Commands, IntoSystemConfigs, ResMut, Update};
     use bevy_test_helper::resource::bool::{Bool, BoolExtension};
-    use crate::action::once;
-    use crate::prelude::{switch_just_turned_off, switch_just_turned_on};
+    use crate::action::{delay, once};
+    use crate::prelude::{switch_just_turned_off, switch_just_turned_on, Pipe, Switch, SwitchComponent, SwitchExtension, Then};
     use crate::reactor::Reactor;
     use crate::tests::test_app;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
 
     struct T;
 
@@ -139,4 +345,122 @@ mod tests {
         app.update();
         assert!(app.is_bool_true());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn once_switch_toggle() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::switch::toggle::<T>()).await;
+                task.will(Update, once::switch::toggle::<T>()
+                    .then(once::switch::toggle::<T>())
+                    .then(once::switch::toggle::<T>()),
+                ).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_on());
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_off());
+    }
+
+    #[test]
+    fn once_switch_on_with_payload() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::switch::on_with::<T, usize>().with(3)).await;
+            }));
+        });
+
+        app.update();
+        let switch = app.world().resource::<Switch<T, usize>>();
+        assert!(switch.is_on());
+        assert_eq!(switch.payload(), Some(&3));
+    }
+
+    #[test]
+    fn once_switch_on_for_entity() {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::switch::on_for::<T>().with(entity)).await;
+                task.will(Update, once::switch::on_for::<T>()
+                    .with(entity)
+                    .pipe(once::switch::off_for::<T>()),
+                ).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.world().get::<SwitchComponent<T>>(entity).unwrap().is_on());
+        app.update();
+        assert!(app.world().get::<SwitchComponent<T>>(entity).unwrap().is_off());
+    }
+
+    #[test]
+    fn once_switch_on_for_duration() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::switch::on_for_duration::<T>().with(Duration::from_millis(300))).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_on());
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_on());
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().resource::<Switch<T>>().is_off());
+    }
+
+    #[test]
+    fn once_switch_on_for_duration_in_first() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, once::switch::on_for_duration::<T>().with(Duration::from_millis(300))).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_on());
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(app.world().resource::<Switch<T>>().is_off());
+    }
+
+    #[test]
+    fn once_switch_group_off() {
+        struct A;
+        struct B;
+
+        let mut app = test_app();
+        app
+            .add_switch_to_group::<A>("combat")
+            .add_switch_to_group::<B>("combat")
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, once::switch::group_on("combat")
+                        .then(delay::frames().with(1))
+                        .then(once::switch::group_off("combat")),
+                    ).await;
+                }));
+            });
+
+        app.update();
+        assert!(app.world().resource::<Switch<A>>().is_on());
+        assert!(app.world().resource::<Switch<B>>().is_on());
+        app.update();
+        assert!(app.world().resource::<Switch<A>>().is_off());
+        assert!(app.world().resource::<Switch<B>>().is_off());
+    }
+}
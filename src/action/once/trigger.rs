@@ -0,0 +1,116 @@
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::ecs::observer::TriggerTargets;
+use bevy::prelude::{Event, World};
+
+/// Once trigger `event` via [`World::trigger`], so that observers of `E` run immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event)]
+/// struct Explode;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::trigger(Explode)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn trigger<E>(event: E) -> ActionSeed
+where
+    E: Event,
+{
+    let mut event = Some(event);
+    once::run(move |world: &mut World| {
+        if let Some(event) = event.take() {
+            world.trigger(event);
+        }
+    })
+}
+
+/// Once trigger `event` for `targets` via [`World::trigger_targets`],
+/// so that observers of `E` watching the targets run immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event)]
+/// struct Damage(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let enemy = task.will(Update, once::entity::spawn().with(())).await;
+///     task.will(Update, once::trigger_targets(Damage(3), enemy)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn trigger_targets<E, Targets>(event: E, targets: Targets) -> ActionSeed
+where
+    E: Event,
+    Targets: TriggerTargets + Send + Sync + 'static,
+{
+    let mut args = Some((event, targets));
+    once::run(move |world: &mut World| {
+        if let Some((event, targets)) = args.take() {
+            world.trigger_targets(event, targets);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::Reactor;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Event, ResMut, Trigger};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Event)]
+    struct TestTrigger(usize);
+
+    #[derive(Component)]
+    struct Target;
+
+    #[test]
+    fn trigger_event() {
+        let mut app = test_app();
+        app.add_observer(|trigger: Trigger<TestTrigger>, mut count: ResMut<Count>| {
+            count.0 += trigger.event().0;
+        });
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::trigger(TestTrigger(3))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn trigger_event_for_targets() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn(Target).id();
+        app.world_mut().spawn(Target).observe(|_: Trigger<TestTrigger>, mut count: ResMut<Count>| {
+            count.0 += 10;
+        });
+        app.world_mut().entity_mut(target).observe(|trigger: Trigger<TestTrigger>, mut count: ResMut<Count>| {
+            count.0 += trigger.event().0;
+        });
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::trigger_targets(TestTrigger(2), target)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+}
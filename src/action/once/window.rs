@@ -0,0 +1,188 @@
+//! [`once::window`] creates a task that only once run system related to the primary [`Window`].
+//!
+//! These actions do nothing if there is no primary window.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::prelude::{In, Query, With};
+use bevy::window::{CursorGrabMode, PrimaryWindow, Window, WindowMode};
+
+/// Once set the cursor grab mode of the primary window.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::CursorGrabMode;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::window::grab_cursor().with(CursorGrabMode::Locked)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn grab_cursor() -> ActionSeed<CursorGrabMode> {
+    once::run(|In(mode): In<CursorGrabMode>, mut windows: Query<&mut Window, With<PrimaryWindow>>| {
+        for mut window in windows.iter_mut() {
+            window.cursor_options.grab_mode = mode;
+        }
+    })
+}
+
+/// Once release the cursor of the primary window.
+///
+/// This is the same as passing [`CursorGrabMode::None`] to [`grab_cursor`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::window::release_cursor()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn release_cursor() -> ActionSeed {
+    once::run(|mut windows: Query<&mut Window, With<PrimaryWindow>>| {
+        for mut window in windows.iter_mut() {
+            window.cursor_options.grab_mode = CursorGrabMode::None;
+        }
+    })
+}
+
+/// Once show the cursor over the primary window.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::window::show_cursor()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn show_cursor() -> ActionSeed {
+    set_cursor_visible(true)
+}
+
+/// Once hide the cursor over the primary window.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::window::hide_cursor()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn hide_cursor() -> ActionSeed {
+    set_cursor_visible(false)
+}
+
+/// Once set the [`WindowMode`] of the primary window, such as fullscreen.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::WindowMode;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::window::set_mode().with(WindowMode::Windowed)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn set_mode() -> ActionSeed<WindowMode> {
+    once::run(|In(mode): In<WindowMode>, mut windows: Query<&mut Window, With<PrimaryWindow>>| {
+        for mut window in windows.iter_mut() {
+            window.mode = mode;
+        }
+    })
+}
+
+fn set_cursor_visible(visible: bool) -> ActionSeed {
+    once::run(move |mut windows: Query<&mut Window, With<PrimaryWindow>>| {
+        for mut window in windows.iter_mut() {
+            window.cursor_options.visible = visible;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::Reactor;
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Commands, Entity};
+    use bevy::window::{CursorGrabMode, MonitorSelection, PrimaryWindow, Window, WindowMode};
+
+    fn spawn_windows(app: &mut App) -> (Entity, Entity) {
+        let primary = app.world_mut().spawn((Window::default(), PrimaryWindow)).id();
+        let other = app.world_mut().spawn(Window::default()).id();
+        (primary, other)
+    }
+
+    fn window(app: &App, entity: Entity) -> &Window {
+        app.world().get::<Window>(entity).unwrap()
+    }
+
+    #[test]
+    fn grab_and_release_cursor() {
+        let mut app = test_app();
+        let (primary, other) = spawn_windows(&mut app);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::window::grab_cursor().with(CursorGrabMode::Locked)).await;
+                task.will(Update, once::window::release_cursor()).await;
+            }));
+        });
+        app.update();
+        assert_eq!(window(&app, primary).cursor_options.grab_mode, CursorGrabMode::Locked);
+        assert_eq!(window(&app, other).cursor_options.grab_mode, CursorGrabMode::None);
+
+        app.update();
+        assert_eq!(window(&app, primary).cursor_options.grab_mode, CursorGrabMode::None);
+    }
+
+    #[test]
+    fn hide_and_show_cursor() {
+        let mut app = test_app();
+        let (primary, other) = spawn_windows(&mut app);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::window::hide_cursor()).await;
+                task.will(Update, once::window::show_cursor()).await;
+            }));
+        });
+        app.update();
+        assert!(!window(&app, primary).cursor_options.visible);
+        assert!(window(&app, other).cursor_options.visible);
+
+        app.update();
+        assert!(window(&app, primary).cursor_options.visible);
+    }
+
+    #[test]
+    fn set_window_mode() {
+        let mut app = test_app();
+        let (primary, other) = spawn_windows(&mut app);
+        let mode = WindowMode::BorderlessFullscreen(MonitorSelection::Primary);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, once::window::set_mode().with(mode)).await;
+            }));
+        });
+        app.update();
+        assert_eq!(window(&app, primary).mode, mode);
+        assert_eq!(window(&app, other).mode, WindowMode::Windowed);
+    }
+}
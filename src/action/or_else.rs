@@ -0,0 +1,165 @@
+//! Provides the mechanism to fall back to another action when an action fails.
+//!
+//! trait
+//!
+//! - [`OrElse`]
+//! - [`Fallible`]
+
+use crate::action::remake::Remake;
+use crate::action::Action;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+
+/// Represents the output of an action that may fail.
+///
+/// It is implemented for [`Option`] and [`Result`].
+pub trait Fallible {
+    /// Returns true if the value represents a failure, such as `None` or `Err`.
+    fn is_failure(&self) -> bool;
+}
+
+impl<T> Fallible for Option<T> {
+    #[inline(always)]
+    fn is_failure(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T, E> Fallible for Result<T, E> {
+    #[inline(always)]
+    fn is_failure(&self) -> bool {
+        self.is_err()
+    }
+}
+
+/// Provides the mechanism to run a fallback action if an action fails.
+pub trait OrElse<I1, O1, ActionOrSeed> {
+    /// Creates an action that outputs the output of this action if it succeeded,
+    /// otherwise runs `fallback` and outputs its output instead.
+    ///
+    /// Whether the output is a failure is determined by [`Fallible`].
+    /// If this action succeeds, `fallback` is never run.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let data: Option<String> = task.will(Update, once::run(|| None)
+    ///         .or_else(once::run(|| Some("fetched".to_string())))
+    ///     ).await;
+    ///     assert_eq!(data.as_deref(), Some("fetched"));
+    /// });
+    /// ```
+    fn or_else<I2>(self, fallback: impl Into<Action<I2, O1>> + Send + Sync + 'static) -> ActionOrSeed
+    where
+        I2: 'static;
+}
+
+impl<I1, O1, A, Re> OrElse<I1, O1, A> for Re
+where
+    I1: 'static,
+    O1: Fallible + 'static,
+    Re: Remake<I1, O1, O1, A> + 'static,
+{
+    #[inline]
+    fn or_else<I2>(self, fallback: impl Into<Action<I2, O1>> + Send + Sync + 'static) -> A
+    where
+        I2: 'static,
+    {
+        self.remake(|r1, o1, output| OrElseRunner {
+            r1,
+            o1,
+            r2: None,
+            fallback: Some(fallback.into()),
+            output,
+        })
+    }
+}
+
+struct OrElseRunner<I2, O> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    r2: Option<BoxedRunner>,
+    fallback: Option<Action<I2, O>>,
+    output: Output<O>,
+}
+
+impl<I2, O> Runner for OrElseRunner<I2, O>
+where
+    I2: 'static,
+    O: Fallible + 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if self.r2.is_none() {
+            match self.r1.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => return RunnerIs::Running,
+                RunnerIs::Completed => {}
+            }
+            let o = self.o1.take().expect("An output value hasn't been set!!!");
+            if !o.is_failure() {
+                self.output.set(o);
+                return RunnerIs::Completed;
+            }
+            let fallback = self.fallback.take().expect("Failed to take the fallback action");
+            self.r2.replace(fallback.create_runner(self.output.clone()));
+        }
+
+        self.r2
+            .as_mut()
+            .map(|r2| r2.run(world, token))
+            .unwrap_or(RunnerIs::Canceled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::or_else::OrElse;
+    use crate::action::once;
+    use crate::prelude::{delay, Pipe, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn not_run_fallback_if_succeeded() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| Ok::<usize, ()>(1))
+                    .or_else(once::run(|| Ok(2)))
+                    .pipe(once::run(|In(result): In<Result<usize, ()>>, mut count: ResMut<Count>| {
+                        count.0 = result.unwrap();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_fallback_if_none() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| None)
+                    .or_else(delay::frames().with(1).then(once::run(|| Some(2))))
+                    .pipe(once::run(|In(num): In<Option<usize>>, mut count: ResMut<Count>| {
+                        count.0 = num.unwrap();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+}
@@ -10,6 +10,12 @@ use crate::runner::{BoxedRunner, CancellationHandlers, Output, Runner, RunnerIs}
 pub trait Pipe<I1, O1, O2, A> {
     /// Combine this action and the passed [`ActionSeed`].
     ///
+    /// The output of this action is passed as the input of `seed`,
+    /// and both are run within a single [`ReactorTask::will`](crate::prelude::ReactorTask::will).
+    /// Since the combined action is just a value, it can be stored or returned from functions.
+    ///
+    /// If you don't need the output, use [`Then::then`](crate::prelude::Then::then) instead.
+    ///
     /// ## Examples
     ///
     /// ```no_run
@@ -109,15 +115,35 @@ where
 #[cfg(test)]
 mod tests {
     use crate::action::{delay, once};
-    use crate::prelude::{Map, Pipe, Reactor, Then, Through};
+    use crate::prelude::{ActionSeed, Map, Pipe, Reactor, Then, Through};
     use crate::test_util::test;
     use crate::tests::{increment_count, test_app};
     use bevy::app::{AppExit, Startup};
-    use bevy::prelude::{Commands, Events, Update};
+    use bevy::prelude::{Commands, Events, In, ResMut, Update};
     use bevy_test_helper::event::DirectEvents;
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
+    fn double_then_store() -> ActionSeed<usize> {
+        once::run(|In(num): In<usize>| num * 2)
+            .pipe(once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+                count.0 = num;
+            }))
+    }
+
+    #[test]
+    fn pipe_stored_pipeline() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let pipeline = once::run(|| 3).pipe(double_then_store());
+                task.will(Update, pipeline).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(6));
+    }
+
     /// Make sure `Option::unwrap() on a None` does not occur.
     #[test]
     fn not_occur_unwrap_panic() {
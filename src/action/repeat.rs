@@ -0,0 +1,506 @@
+//! Provides the actions that run an action repeatedly.
+//!
+//! actions
+//!
+//! - [`repeat::count`](crate::prelude::repeat::count)
+//! - [`repeat::forever`](crate::prelude::repeat::forever)
+//! - [`repeat::until`](crate::prelude::repeat::until)
+//! - [`repeat::whilst`](crate::prelude::repeat::whilst)
+//! - [`repeat::fold`](crate::prelude::repeat::fold)
+
+use crate::action::Action;
+use crate::prelude::{ActionSeed, CancellationHandlers};
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::{IntoSystem, System, World};
+use std::ops::ControlFlow;
+
+/// Runs the action created by `factory` `count` times in sequence.
+///
+/// If an action completes, the next one starts within the same frame.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, repeat::count(3, || {
+///         once::run(|| println!("blink"))
+///             .then(delay::time().with(Duration::from_millis(100)))
+///     })).await;
+/// });
+/// ```
+#[inline]
+pub fn count<F, A, I, O>(count: usize, factory: F) -> ActionSeed
+where
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| RepeatRunner {
+        factory: runner_factory(factory),
+        limit: Some(count),
+        runner: None,
+        output,
+    })
+}
+
+/// Runs the action created by `factory` repeatedly until the reactor is despawned.
+///
+/// To avoid blocking the frame, the next action starts from the next frame
+/// after the previous one completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, repeat::forever(|| {
+///         wait::input::just_pressed().with(KeyCode::Space)
+///             .then(once::run(|| println!("jump")))
+///     })).await;
+/// });
+/// ```
+#[inline]
+pub fn forever<F, A, I, O>(factory: F) -> ActionSeed
+where
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| RepeatRunner {
+        factory: runner_factory(factory),
+        limit: None,
+        runner: None,
+        output,
+    })
+}
+
+/// Runs the action created by `factory` repeatedly until `condition` returns true.
+///
+/// `condition` is evaluated each time the action completes,
+/// so the action is always run at least once.
+/// The output is that of the last run action.
+///
+/// To avoid blocking the frame, the next action starts from the next frame
+/// after the previous one completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Hp(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let last_damage: usize = task.will(Update, repeat::until(
+///         |hp: Res<Hp>| hp.0 == 0,
+///         || once::run(|mut hp: ResMut<Hp>| {
+///             hp.0 = hp.0.saturating_sub(10);
+///             10
+///         }),
+///     )).await;
+/// });
+/// ```
+#[inline]
+pub fn until<Sys, M, F, A, I, O>(condition: Sys, factory: F) -> ActionSeed<(), O>
+where
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    conditional(condition, factory, true)
+}
+
+/// Runs the action created by `factory` repeatedly while `condition` returns true.
+///
+/// `condition` is evaluated each time the action completes,
+/// so the action is always run at least once.
+/// The output is that of the last run action.
+///
+/// To avoid blocking the frame, the next action starts from the next frame
+/// after the previous one completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Connecting;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, repeat::whilst(
+///         switch_is_on::<Connecting>,
+///         || once::run(|| println!("polling...")),
+///     )).await;
+/// });
+/// ```
+#[inline]
+pub fn whilst<Sys, M, F, A, I, O>(condition: Sys, factory: F) -> ActionSeed<(), O>
+where
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    conditional(condition, factory, false)
+}
+
+fn conditional<Sys, M, F, A, I, O>(condition: Sys, factory: F, stop_if: bool) -> ActionSeed<(), O>
+where
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| {
+        let mut factory = factory;
+        ConditionalRepeatRunner {
+            factory: move || {
+                let o = Output::default();
+                let action: Action<I, O> = factory().into();
+                (action.create_runner(o.clone()), o)
+            },
+            condition: IntoSystem::into_system(condition),
+            stop_if,
+            init: false,
+            runner: None,
+            output,
+        }
+    })
+}
+
+/// Runs the action created by `factory` repeatedly,
+/// folding each output into an accumulator with `f`.
+///
+/// It completes when `f` returns [`ControlFlow::Break`],
+/// and the value of `Break` becomes the output.
+///
+/// To avoid blocking the frame, the next action starts from the next frame
+/// after the previous one completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::ops::ControlFlow;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct PickedUp(Entity);
+///
+/// Reactor::schedule(|task| async move{
+///     // Gather 5 items.
+///     let items: Vec<Entity> = task.will(Update, repeat::fold(
+///         Vec::new(),
+///         || wait::event::read::<PickedUp>(),
+///         |mut items, PickedUp(item)| {
+///             items.push(item);
+///             if items.len() == 5 {
+///                 ControlFlow::Break(items)
+///             } else {
+///                 ControlFlow::Continue(items)
+///             }
+///         },
+///     )).await;
+/// });
+/// ```
+pub fn fold<Acc, F, A, I, O, G>(init: Acc, factory: F, f: G) -> ActionSeed<(), Acc>
+where
+    Acc: Send + Sync + 'static,
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+    G: FnMut(Acc, O) -> ControlFlow<Acc, Acc> + Send + Sync + 'static,
+{
+    ActionSeed::new(move |_, output| {
+        let mut factory = factory;
+        FoldRunner {
+            factory: move || {
+                let o = Output::default();
+                let action: Action<I, O> = factory().into();
+                (action.create_runner(o.clone()), o)
+            },
+            f,
+            acc: Some(init),
+            runner: None,
+            output,
+        }
+    })
+}
+
+#[inline]
+fn runner_factory<F, A, I, O>(mut factory: F) -> impl FnMut() -> BoxedRunner
+where
+    F: FnMut() -> A,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    move || factory().into().create_runner(Output::default())
+}
+
+struct RepeatRunner<F> {
+    factory: F,
+    limit: Option<usize>,
+    runner: Option<BoxedRunner>,
+    output: Output<()>,
+}
+
+impl<F> Runner for RepeatRunner<F>
+where
+    F: FnMut() -> BoxedRunner,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        loop {
+            if self.limit == Some(0) {
+                self.output.set(());
+                return RunnerIs::Completed;
+            }
+            let runner = self.runner.get_or_insert_with(&mut self.factory);
+            match runner.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => return RunnerIs::Running,
+                RunnerIs::Completed => {}
+            }
+            self.runner = None;
+            match self.limit.as_mut() {
+                Some(limit) => *limit -= 1,
+                None => return RunnerIs::Running,
+            }
+        }
+    }
+}
+
+struct ConditionalRepeatRunner<F, Sys, O> {
+    factory: F,
+    condition: Sys,
+    stop_if: bool,
+    init: bool,
+    runner: Option<(BoxedRunner, Output<O>)>,
+    output: Output<O>,
+}
+
+impl<F, Sys, O> Runner for ConditionalRepeatRunner<F, Sys, O>
+where
+    F: FnMut() -> (BoxedRunner, Output<O>),
+    Sys: System<In=(), Out=bool>,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let (runner, o) = self.runner.get_or_insert_with(&mut self.factory);
+        match runner.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Running => return RunnerIs::Running,
+            RunnerIs::Completed => {}
+        }
+        let out = o.take().expect("An output value hasn't been set!!!");
+        self.runner = None;
+
+        if !self.init {
+            self.condition.initialize(world);
+            self.init = true;
+        }
+        let condition = self.condition.run((), world);
+        self.condition.apply_deferred(world);
+        if condition == self.stop_if {
+            self.output.set(out);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+struct FoldRunner<F, G, Acc, O> {
+    factory: F,
+    f: G,
+    acc: Option<Acc>,
+    runner: Option<(BoxedRunner, Output<O>)>,
+    output: Output<Acc>,
+}
+
+impl<F, G, Acc, O> Runner for FoldRunner<F, G, Acc, O>
+where
+    F: FnMut() -> (BoxedRunner, Output<O>),
+    G: FnMut(Acc, O) -> ControlFlow<Acc, Acc>,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let (runner, o) = self.runner.get_or_insert_with(&mut self.factory);
+        match runner.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Running => return RunnerIs::Running,
+            RunnerIs::Completed => {}
+        }
+        let out = o.take().expect("An output value hasn't been set!!!");
+        self.runner = None;
+
+        let acc = self.acc.take().expect("Failed to take the accumulator");
+        match (self.f)(acc, out) {
+            ControlFlow::Continue(acc) => {
+                self.acc.replace(acc);
+                RunnerIs::Running
+            }
+            ControlFlow::Break(acc) => {
+                self.output.set(acc);
+                RunnerIs::Completed
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::repeat;
+    use crate::prelude::{delay, once, Pipe, Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Res, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::ops::ControlFlow;
+
+    #[test]
+    fn repeat_count_in_same_frame() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::count(3, increment_count)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn repeat_count_zero() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::count(0, increment_count).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn repeat_count_with_delay() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::count(2, || delay::frames().with(1).then(increment_count()))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn repeat_forever_once_per_frame() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::forever(increment_count)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn repeat_until_output_last() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::until(
+                    |count: Res<Count>| count.0 == 3,
+                    || once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                        count.0 * 10
+                    }),
+                )
+                    .pipe(once::run(|In(last): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = last;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(30));
+    }
+
+    #[test]
+    fn repeat_whilst_runs_at_least_once() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::whilst(|| false, increment_count)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn fold_until_break() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::fold(
+                    0,
+                    || once::run(|| 2),
+                    |acc, o| {
+                        let acc = acc + o;
+                        if acc < 6 {
+                            ControlFlow::Continue(acc)
+                        } else {
+                            ControlFlow::Break(acc)
+                        }
+                    },
+                )
+                    .pipe(once::run(|In(sum): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = sum;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(6));
+    }
+}
@@ -0,0 +1,282 @@
+//! Provides the mechanism to re-run fallible actions.
+//!
+//! actions
+//!
+//! - [`retry`]
+//! - [`retry_with_backoff`]
+
+use crate::action::Action;
+use crate::prelude::{ActionSeed, CancellationHandlers};
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::{TimerMode, World};
+use bevy::time::{Time, Timer};
+use std::time::Duration;
+
+/// Represents how long to wait before the next attempt of [`retry_with_backoff`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Backoff {
+    /// Retries immediately.
+    #[default]
+    None,
+
+    /// Waits for the same duration before each retry.
+    Fixed(Duration),
+
+    /// Doubles the waiting duration after each failed attempt, starting with `initial`.
+    ///
+    /// The waiting duration never exceeds `max`.
+    Exponential {
+        /// The duration to wait before the first retry.
+        initial: Duration,
+        /// The upper limit of the duration.
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// Returns the duration to wait after the `failures`-th failed attempt.
+    pub fn delay(&self, failures: u32) -> Duration {
+        match self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Fixed(duration) => *duration,
+            Backoff::Exponential { initial, max } => {
+                let exp = failures.saturating_sub(1).min(31);
+                initial.saturating_mul(1 << exp).min(*max)
+            }
+        }
+    }
+}
+
+/// Creates an action that re-runs a fallible action until it outputs `Ok`.
+///
+/// `factory` is called to create the action before each attempt.
+/// If all `attempts` fail, the last error is output.
+///
+/// This is equivalent to [`retry_with_backoff`] with [`Backoff::None`].
+///
+/// # Panics
+///
+/// Panicked if `attempts` is 0.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result: Result<(), String> = task.will(Update, retry(3, || once::run(|| {
+///         Err("Connection refused".to_string())
+///     }))).await;
+///     assert!(result.is_err());
+/// });
+/// ```
+#[inline]
+pub fn retry<F, A, I, O, E>(attempts: usize, factory: F) -> ActionSeed<(), Result<O, E>>
+where
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, Result<O, E>>>,
+    I: 'static,
+    O: 'static,
+    E: 'static,
+{
+    retry_with_backoff(attempts, Backoff::None, factory)
+}
+
+/// Creates an action that re-runs a fallible action until it outputs `Ok`,
+/// waiting for the duration specified by [`Backoff`] between attempts.
+///
+/// `factory` is called to create the action before each attempt.
+/// If all `attempts` fail, the last error is output.
+///
+/// The waiting duration is measured by [`Time`] of the schedule in which the action is running.
+///
+/// # Panics
+///
+/// Panicked if `attempts` is 0.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let backoff = Backoff::Exponential {
+///         initial: Duration::from_millis(100),
+///         max: Duration::from_secs(1),
+///     };
+///     let result: Result<usize, ()> = task.will(Update, retry_with_backoff(5, backoff, || once::run(|| {
+///         Ok(1)
+///     }))).await;
+/// });
+/// ```
+pub fn retry_with_backoff<F, A, I, O, E>(
+    attempts: usize,
+    backoff: Backoff,
+    factory: F,
+) -> ActionSeed<(), Result<O, E>>
+where
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, Result<O, E>>>,
+    I: 'static,
+    O: 'static,
+    E: 'static,
+{
+    if attempts == 0 {
+        panic!("The number of attempts passed to `retry` must be greater than 0.");
+    }
+    ActionSeed::new(move |_, output| {
+        let mut factory = factory;
+        RetryRunner {
+            factory: move || {
+                let o = Output::default();
+                let action: Action<I, Result<O, E>> = factory().into();
+                (action.create_runner(o.clone()), o)
+            },
+            backoff,
+            attempts,
+            failures: 0,
+            runner: None,
+            wait: None,
+            output,
+        }
+    })
+}
+
+struct RetryRunner<F, O, E> {
+    factory: F,
+    backoff: Backoff,
+    attempts: usize,
+    failures: u32,
+    runner: Option<(BoxedRunner, Output<Result<O, E>>)>,
+    wait: Option<Timer>,
+    output: Output<Result<O, E>>,
+}
+
+impl<F, O, E> Runner for RetryRunner<F, O, E>
+where
+    F: FnMut() -> (BoxedRunner, Output<Result<O, E>>),
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        loop {
+            if let Some(timer) = self.wait.as_mut() {
+                if !timer.tick(world.resource::<Time>().delta()).finished() {
+                    return RunnerIs::Running;
+                }
+                self.wait = None;
+            }
+
+            let (runner, o) = self.runner.get_or_insert_with(&mut self.factory);
+            match runner.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => return RunnerIs::Running,
+                RunnerIs::Completed => {}
+            }
+            let result = o.take().expect("An output value hasn't been set!!!");
+            self.runner = None;
+            match result {
+                Ok(o) => {
+                    self.output.set(Ok(o));
+                    return RunnerIs::Completed;
+                }
+                Err(e) => {
+                    self.failures += 1;
+                    if self.attempts <= self.failures as usize {
+                        self.output.set(Err(e));
+                        return RunnerIs::Completed;
+                    }
+                    let delay = self.backoff.delay(self.failures);
+                    if !delay.is_zero() {
+                        self.wait.replace(Timer::new(delay, TimerMode::Once));
+                        return RunnerIs::Running;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::retry::{retry, retry_with_backoff, Backoff};
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn succeed_on_third_attempt() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, retry(5, || once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                    if count.0 == 3 {
+                        Ok(count.0)
+                    } else {
+                        Err(())
+                    }
+                }))).await;
+                assert_eq!(result, Ok(3));
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn output_last_error_if_all_attempts_failed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, retry(2, || once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                    Err::<(), usize>(count.0)
+                }))
+                    .pipe(once::run(|In(result): In<Result<(), usize>>, mut count: ResMut<Count>| {
+                        count.0 = result.unwrap_err() * 10;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(20));
+    }
+
+    #[test]
+    fn wait_for_backoff_between_attempts() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, retry_with_backoff(3, Backoff::Fixed(Duration::from_secs(10)), || once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                    Err::<(), ()>(())
+                }))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let backoff = Backoff::Exponential {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(500),
+        };
+        assert_eq!(backoff.delay(1), Duration::from_millis(100));
+        assert_eq!(backoff.delay(2), Duration::from_millis(200));
+        assert_eq!(backoff.delay(3), Duration::from_millis(400));
+        assert_eq!(backoff.delay(4), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,66 @@
+//! Provides the random number generator used by the actions.
+
+use bevy::prelude::Resource;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+/// The random number generator used by actions such as [`branch::pick_random`](crate::prelude::branch::pick_random).
+///
+/// If this resource doesn't exist when needed, it is inserted with the default generator,
+/// a xorshift generator seeded randomly.
+/// You can replace it by inserting this resource with your own generator.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let mut app = App::new();
+/// // Always returns 0.5.
+/// app.insert_resource(ActionRng::new(|| 0.5));
+/// ```
+#[derive(Resource)]
+pub struct ActionRng(Box<dyn FnMut() -> f32 + Send + Sync>);
+
+impl ActionRng {
+    /// Creates a new generator from `f`.
+    ///
+    /// `f` must return a value in the range `[0, 1)`.
+    #[inline]
+    pub fn new(f: impl FnMut() -> f32 + Send + Sync + 'static) -> Self {
+        Self(Box::new(f))
+    }
+
+    /// Returns the next random value in the range `[0, 1)`.
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        (self.0)()
+    }
+}
+
+impl Default for ActionRng {
+    fn default() -> Self {
+        let mut state = RandomState::new().build_hasher().finish() | 1;
+        Self::new(move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1u64 << 24) as f32
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::rng::ActionRng;
+
+    #[test]
+    fn default_rng_in_range() {
+        let mut rng = ActionRng::default();
+        for _ in 0..1000 {
+            let v = rng.next_f32();
+            assert!((0.0..1.0).contains(&v));
+        }
+    }
+}
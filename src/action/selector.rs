@@ -0,0 +1,148 @@
+//! A selector is a structure that represents one of multiple states, such as the variants of an enum.
+//!
+//! This generalizes [`Switch`](crate::prelude::Switch) beyond `on` and `off`,
+//! so systems running on other threads can react to the state selected by `Reactors`.
+
+use bevy::prelude::{Res, Resource};
+use std::marker::PhantomData;
+
+/// Creates a Condition-satisfying system that returns true if the selector is `state`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Door;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum DoorState{
+///     Opened,
+///     Closed,
+///     Locked,
+/// }
+///
+/// App::new()
+///     .add_systems(Update, (|| {
+///         println!("locked");
+///     }).run_if(selector_is::<Door, _>(DoorState::Locked)));
+/// ```
+#[inline]
+pub fn selector_is<M, S>(state: S) -> impl FnMut(Option<Res<Selector<M, S>>>) -> bool + Clone
+    where
+        M: Send + Sync + 'static,
+        S: PartialEq + Clone + Send + Sync + 'static
+{
+    move |selector: Option<Res<Selector<M, S>>>| {
+        selector.is_some_and(|selector| selector.is(&state))
+    }
+}
+
+/// A selector is a structure that holds one of multiple states of type `S`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Door;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum DoorState{
+///     Opened,
+///     Closed,
+/// }
+///
+/// App::new()
+///     .add_systems(Update, (|mut selector: ResMut<Selector<Door, DoorState>>|{
+///         // open the door
+///         //...
+///
+///         selector.set(DoorState::Opened);
+///     }).run_if(selector_is::<Door, _>(DoorState::Closed)))
+///     .add_systems(Update, |mut commands: Commands|{
+///         commands.spawn(Reactor::schedule(|task| async move{
+///             task.will(Update, once::selector::set::<Door, _>().with(DoorState::Closed)).await;
+///             task.will(Update, wait::selector::becomes::<Door, _>().with(DoorState::Opened)).await;
+///         }));
+///     });
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct Selector<M, S> {
+    state: S,
+    _m: PhantomData<M>,
+}
+
+impl<M, S> Resource for Selector<M, S>
+    where
+        M: Send + Sync + 'static,
+        S: Send + Sync + 'static
+{}
+
+impl<M, S> Selector<M, S>
+    where
+        M: Send + Sync + 'static,
+        S: Send + Sync + 'static
+{
+    /// Create new Selector with initial state.
+    #[inline(always)]
+    pub const fn new(state: S) -> Selector<M, S> {
+        Self {
+            state,
+            _m: PhantomData,
+        }
+    }
+
+    /// Returns the current state.
+    #[inline(always)]
+    pub const fn get(&self) -> &S {
+        &self.state
+    }
+
+    /// Selects the state.
+    #[inline(always)]
+    pub fn set(&mut self, state: S) {
+        self.state = state;
+    }
+
+    /// Returns true if the current state is `state`.
+    #[inline(always)]
+    pub fn is(&self, state: &S) -> bool
+        where S: PartialEq
+    {
+        &self.state == state
+    }
+}
+
+impl<M, S> Default for Selector<M, S>
+    where
+        M: Send + Sync + 'static,
+        S: Default + Send + Sync + 'static
+{
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::Selector;
+
+    struct T;
+
+    #[derive(Debug, PartialEq)]
+    enum S {
+        A,
+        B,
+    }
+
+    #[test]
+    fn set() {
+        let mut s = Selector::<T, S>::new(S::A);
+        assert!(s.is(&S::A));
+        s.set(S::B);
+        assert_eq!(s.get(), &S::B);
+    }
+}
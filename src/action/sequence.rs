@@ -4,11 +4,23 @@
 //! in method chains like `once::run(||{}).then(once::run(||{}))` 
 //!
 //! It also provides the [`sequence!`](crate::sequence) macro. The behavior itself is the same as [`Then`].
+//!
+//! If the actions are not known at compile time, use [`from_iter`] instead,
+//! or [`budgeted`] to spread them across frames.
 
 use crate::action::{Action, Remake};
-use crate::prelude::CancellationHandlers;
+use crate::prelude::{ActionSeed, CancellationHandlers};
 use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
 use bevy::prelude::World;
+use bevy::utils::Instant;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// An action whose input has already been given.
+///
+/// Since the input type is erased, actions with different input types can be stored together.
+/// Any action can be converted into it with [`OmitInput::omit_input`](crate::prelude::OmitInput::omit_input).
+pub type BoxedAction<O = ()> = ActionSeed<(), O>;
 
 /// Create the action combined with the subsequent action.
 ///
@@ -20,6 +32,8 @@ pub trait Then<I1, O1, O2, ActionOrSeed> {
     /// Returns the action combined with the subsequent action.
     ///
     /// The action's output will be that of the subsequent action.
+    /// The output of this action is discarded;
+    /// use [`Pipe::pipe`](crate::prelude::Pipe::pipe) instead to feed it into the subsequent action.
     ///
     /// ## Examples
     ///
@@ -103,6 +117,139 @@ macro_rules! sequence {
     };
 }
 
+/// Creates an action that executes the passed actions in sequence.
+///
+/// Unlike [`sequence!`](crate::sequence), the actions can be built at runtime, for example from data.
+/// If the previous action finishes, the next will start within that frame,
+/// and if no actions are passed, it completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let lines = ["Hello", "How are you?"];
+/// Reactor::schedule(move |task| async move{
+///     let steps: Vec<BoxedAction> = lines
+///         .into_iter()
+///         .map(|line| {
+///             once::run(move || println!("{line}"))
+///                 .then(wait::input::just_pressed().with(KeyCode::Enter))
+///                 .omit_input()
+///         })
+///         .collect();
+///     task.will(Update, sequence::from_iter(steps)).await;
+/// });
+/// ```
+pub fn from_iter<Actions>(actions: Actions) -> ActionSeed
+where
+    Actions: IntoIterator<Item=BoxedAction>,
+{
+    let actions = actions.into_iter().collect::<VecDeque<_>>();
+    ActionSeed::new(move |_, output| IterSequenceRunner {
+        actions,
+        runner: None,
+        output,
+    })
+}
+
+/// Creates an action that executes the passed actions in sequence,
+/// running as many of them as fit within `budget` each frame.
+///
+/// When the time spent in the current frame exceeds `budget`,
+/// it yields and resumes from the next action in the next frame.
+/// At least one action is run each frame, so an action that takes longer than `budget` is still run.
+///
+/// This is useful for heavy work, such as level generation, which would stall a frame if run at once.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let chunks: Vec<BoxedAction> = (0..100)
+///         .map(|i| once::run(move || println!("generate chunk {i}")))
+///         .collect();
+///     task.will(Update, sequence::budgeted(Duration::from_millis(4), chunks)).await;
+/// });
+/// ```
+pub fn budgeted<Actions>(budget: Duration, actions: Actions) -> ActionSeed
+where
+    Actions: IntoIterator<Item=BoxedAction>,
+{
+    let actions = actions.into_iter().collect::<VecDeque<_>>();
+    ActionSeed::new(move |_, output| BudgetedSequenceRunner {
+        budget,
+        actions,
+        runner: None,
+        output,
+    })
+}
+
+struct BudgetedSequenceRunner {
+    budget: Duration,
+    actions: VecDeque<BoxedAction>,
+    runner: Option<BoxedRunner>,
+    output: Output<()>,
+}
+
+impl Runner for BudgetedSequenceRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let start = Instant::now();
+        let mut started = false;
+        loop {
+            if self.runner.is_none() {
+                let Some(seed) = self.actions.pop_front() else {
+                    self.output.set(());
+                    return RunnerIs::Completed;
+                };
+                if started && self.budget <= start.elapsed() {
+                    self.actions.push_front(seed);
+                    return RunnerIs::Running;
+                }
+                self.runner.replace(seed.with(()).create_runner(Output::default()));
+                started = true;
+            }
+            match self.runner.as_mut().unwrap().run(world, token) {
+                RunnerIs::Completed => {
+                    self.runner = None;
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+struct IterSequenceRunner {
+    actions: VecDeque<BoxedAction>,
+    runner: Option<BoxedRunner>,
+    output: Output<()>,
+}
+
+impl Runner for IterSequenceRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        loop {
+            if self.runner.is_none() {
+                let Some(seed) = self.actions.pop_front() else {
+                    self.output.set(());
+                    return RunnerIs::Completed;
+                };
+                self.runner.replace(seed.with(()).create_runner(Output::default()));
+            }
+            match self.runner.as_mut().unwrap().run(world, token) {
+                RunnerIs::Completed => {
+                    self.runner = None;
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
 struct SequenceRunner<O1> {
     pub r1: BoxedRunner,
     pub r2: BoxedRunner,
@@ -133,11 +280,12 @@ mod tests {
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
-    use crate::action::once;
-    use crate::action::sequence::Then;
-    use crate::prelude::Reactor;
+    use crate::action::sequence::{BoxedAction, Then};
+    use crate::action::{delay, once, sequence};
+    use crate::prelude::{OmitInput, Reactor};
     use crate::test_util::test;
     use crate::tests::{increment_count, test_app};
+    use std::time::Duration;
 
     #[derive(Resource, Eq, PartialEq, Debug)]
     struct Mark1;
@@ -253,4 +401,88 @@ mod tests {
         app.update();
         app.assert_resource_eq(Count(0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn from_iter_runs_in_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let steps: Vec<BoxedAction> = vec![
+                    increment_count(),
+                    delay::frames().with(1).omit_input(),
+                    increment_count(),
+                ];
+                task.will(Update, sequence::from_iter(steps)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn from_empty_iter() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, sequence::from_iter(Vec::new())).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn budgeted_yields_when_budget_exceeded() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let steps: Vec<BoxedAction> = (0..3)
+                    .map(|_| increment_count())
+                    .collect();
+                task.will(Update, sequence::budgeted(Duration::ZERO, steps)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn budgeted_completes_in_frame_of_last_action() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let steps: Vec<BoxedAction> = (0..2)
+                    .map(|_| increment_count())
+                    .collect();
+                task.will(Update, sequence::budgeted(Duration::ZERO, steps).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn budgeted_runs_all_within_budget() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let steps: Vec<BoxedAction> = (0..3)
+                    .map(|_| increment_count())
+                    .collect();
+                task.will(Update, sequence::budgeted(Duration::from_secs(60), steps)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+}
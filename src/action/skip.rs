@@ -0,0 +1,210 @@
+//! Provides the mechanism to skip a sequence of actions, such as a cutscene.
+//!
+//! actions
+//!
+//! - [`skippable`]
+//!
+//! enum
+//!
+//! - [`SkipStep`]
+
+use crate::action::sequence::BoxedAction;
+use crate::action::Action;
+use crate::prelude::{ActionSeed, BoxedRunner, OmitInput, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{IntoSystem, System, World};
+use std::collections::VecDeque;
+
+/// A step of [`skippable`].
+pub enum SkipStep {
+    /// The step is dropped when skipped, such as waits and delays.
+    Skippable(BoxedAction),
+
+    /// The step is always run to completion even if skipped,
+    /// such as steps that change the world state.
+    Required(BoxedAction),
+}
+
+impl SkipStep {
+    /// Creates [`SkipStep::Skippable`] from `action`.
+    #[inline]
+    pub fn skippable<I>(action: impl Into<Action<I, ()>> + Send + Sync + 'static) -> Self
+    where
+        I: 'static,
+    {
+        Self::Skippable(action.omit_input())
+    }
+
+    /// Creates [`SkipStep::Required`] from `action`.
+    #[inline]
+    pub fn required<I>(action: impl Into<Action<I, ()>> + Send + Sync + 'static) -> Self
+    where
+        I: 'static,
+    {
+        Self::Required(action.omit_input())
+    }
+}
+
+/// Runs `steps` in sequence until `skip_condition` returns true.
+///
+/// Once skipped, the running and remaining [`SkipStep::Skippable`] steps are dropped,
+/// while the [`SkipStep::Required`] steps are still run in order, so that the world state ends up
+/// the same as if the sequence had not been skipped.
+///
+/// The output is true if the sequence was skipped.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct CameraPos(Vec2);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, skippable(
+///         |keys: Res<ButtonInput<KeyCode>>| keys.just_pressed(KeyCode::Escape),
+///         [
+///             SkipStep::required(once::run(|| println!("cutscene start"))),
+///             SkipStep::skippable(delay::frames().with(120)),
+///             SkipStep::required(once::res::insert().with(CameraPos(Vec2::ONE))),
+///             SkipStep::skippable(delay::frames().with(120)),
+///         ],
+///     )).await;
+/// });
+/// ```
+pub fn skippable<Sys, M, Steps>(skip_condition: Sys, steps: Steps) -> ActionSeed<(), bool>
+where
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+    Steps: IntoIterator<Item=SkipStep>,
+{
+    let steps = steps.into_iter().collect::<VecDeque<_>>();
+    ActionSeed::new(move |_, output| SkippableRunner {
+        skip_condition: IntoSystem::into_system(skip_condition),
+        init: false,
+        steps,
+        runner: None,
+        skipped: false,
+        output,
+    })
+}
+
+struct SkippableRunner<Sys> {
+    skip_condition: Sys,
+    init: bool,
+    steps: VecDeque<SkipStep>,
+    runner: Option<(BoxedRunner, bool)>,
+    skipped: bool,
+    output: Output<bool>,
+}
+
+impl<Sys> SkippableRunner<Sys>
+where
+    Sys: System<In=(), Out=bool>,
+{
+    fn check_skip(&mut self, world: &mut World) {
+        if !self.init {
+            self.skip_condition.initialize(world);
+            self.init = true;
+        }
+        let skip = self.skip_condition.run((), world);
+        self.skip_condition.apply_deferred(world);
+        if skip {
+            self.skipped = true;
+            if self.runner.as_ref().is_some_and(|(_, required)| !required) {
+                self.runner = None;
+            }
+        }
+    }
+}
+
+impl<Sys> Runner for SkippableRunner<Sys>
+where
+    Sys: System<In=(), Out=bool>,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if !self.skipped {
+            self.check_skip(world);
+        }
+
+        loop {
+            if self.runner.is_none() {
+                let (seed, required) = match self.steps.pop_front() {
+                    Some(SkipStep::Skippable(_)) if self.skipped => continue,
+                    Some(SkipStep::Skippable(seed)) => (seed, false),
+                    Some(SkipStep::Required(seed)) => (seed, true),
+                    None => {
+                        self.output.set(self.skipped);
+                        return RunnerIs::Completed;
+                    }
+                };
+                self.runner.replace((seed.with(()).create_runner(Output::default()), required));
+            }
+            let (runner, _) = self.runner.as_mut().unwrap();
+            match runner.run(world, token) {
+                RunnerIs::Completed => {
+                    self.runner = None;
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::skip::{skippable, SkipStep};
+    use crate::action::{delay, once};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Res, ResMut};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn run_all_steps_if_not_skipped() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, skippable(|b: Res<Bool>| **b, [
+                    SkipStep::required(increment_count()),
+                    SkipStep::skippable(delay::frames().with(1)),
+                    SkipStep::skippable(increment_count()),
+                ])).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn run_only_required_steps_after_skipped() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, skippable(|b: Res<Bool>| **b, [
+                    SkipStep::required(increment_count()),
+                    SkipStep::skippable(delay::frames().with(100)),
+                    SkipStep::skippable(increment_count()),
+                    SkipStep::required(increment_count()),
+                ])
+                    .pipe(once::run(|In(skipped): In<bool>, mut count: ResMut<Count>| {
+                        if skipped {
+                            count.0 += 10;
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.set_bool(true);
+        app.update();
+        app.assert_resource_eq(Count(12));
+    }
+}
@@ -3,8 +3,16 @@
 //! This is to solve the problem that systems created from `Reactors`
 //! cannot run except on the main thread.
 
+use std::any::TypeId;
 use std::marker::PhantomData;
-use bevy::prelude::{Local, Mut, Res, Resource, World};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use bevy::app::{App, First, Last};
+use bevy::prelude::{Commands, Component, DetectChangesMut, Entity, Event, EventWriter, Local, Mut, Query, Reflect, ReflectResource, Res, ResMut, Resource, World};
+use bevy::reflect::TypePath;
+use bevy::time::{Time, Timer, TimerMode};
+use bevy::utils::HashMap;
 
 /// A Condition-satisfying system that returns true if the switch has been turned on.
 #[inline]
@@ -67,11 +75,232 @@ pub fn switch_just_turned_off<M>(
     }
 }
 
+/// A Condition-satisfying system that returns true if the switch has been turned on
+/// since the last time this system ran.
+///
+/// Unlike [`switch_just_turned_on`], this is based on [`Switch::transitions`],
+/// so every system using it observes every edge, even if the switch was turned on, off, and on again
+/// between two runs of the system.
+///
+/// The acknowledgement starts at 0, that is, the switch is regarded as off before the first run.
+/// Since a switch created as on counts as turned on once, such as by `Switch::new(true)`,
+/// the first run returns true for it.
+#[inline]
+pub fn switch_just_turned_on_ack<M>(
+    switch: Option<Res<Switch<M>>>,
+    mut acknowledged: Local<u64>,
+) -> bool
+    where M: Send + Sync + 'static
+{
+    switch.is_some_and(|s| {
+        let turned_on = s.turned_on_since(*acknowledged);
+        *acknowledged = s.transitions();
+        turned_on
+    })
+}
+
+/// A Condition-satisfying system that returns true if the switch has been turned off
+/// since the last time this system ran.
+///
+/// Unlike [`switch_just_turned_off`], this is based on [`Switch::transitions`],
+/// so every system using it observes every edge, even if the switch was turned off, on, and off again
+/// between two runs of the system.
+///
+/// The acknowledgement starts at 0, that is, the switch is regarded as off before the first run,
+/// so a switch created as off is not reported until it is turned on and off again.
+#[inline]
+pub fn switch_just_turned_off_ack<M>(
+    switch: Option<Res<Switch<M>>>,
+    mut acknowledged: Local<u64>,
+) -> bool
+    where M: Send + Sync + 'static
+{
+    switch.is_some_and(|s| {
+        let turned_off = s.turned_off_since(*acknowledged);
+        *acknowledged = s.transitions();
+        turned_off
+    })
+}
+
+/// A Condition-satisfying system that returns true if all switches of the tuple `S` are on.
+///
+/// The [`all_switches_on!`](crate::all_switches_on) macro is a shorthand for this.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct HasKey;
+/// struct DoorUnlocked;
+///
+/// App::new()
+///     .add_systems(Update, (|| {
+///         println!("enter");
+///     }).run_if(all_switches_on::<(HasKey, DoorUnlocked)>));
+/// ```
+#[inline]
+pub fn all_switches_on<S>(world: &World) -> bool
+    where S: SwitchTuple
+{
+    S::all_on(world)
+}
+
+/// A Condition-satisfying system that returns true if any switch of the tuple `S` is on.
+///
+/// The [`any_switch_on!`](crate::any_switch_on) macro is a shorthand for this.
+#[inline]
+pub fn any_switch_on<S>(world: &World) -> bool
+    where S: SwitchTuple
+{
+    S::any_on(world)
+}
+
+/// Creates the Condition-satisfying system [`all_switches_on`] from the marker types.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::all_switches_on;
+///
+/// struct A;
+/// struct B;
+/// struct C;
+///
+/// App::new()
+///     .add_systems(Update, (|| {}).run_if(all_switches_on!((A, B, C))));
+/// ```
+#[macro_export]
+macro_rules! all_switches_on {
+    (($($marker: ty),+ $(,)?)) => {
+        $crate::prelude::all_switches_on::<($($marker,)+)>
+    };
+    ($($marker: ty),+ $(,)?) => {
+        $crate::prelude::all_switches_on::<($($marker,)+)>
+    };
+}
+
+/// Creates the Condition-satisfying system [`any_switch_on`] from the marker types.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::any_switch_on;
+///
+/// struct A;
+/// struct B;
+///
+/// App::new()
+///     .add_systems(Update, (|| {}).run_if(any_switch_on!((A, B))));
+/// ```
+#[macro_export]
+macro_rules! any_switch_on {
+    (($($marker: ty),+ $(,)?)) => {
+        $crate::prelude::any_switch_on::<($($marker,)+)>
+    };
+    ($($marker: ty),+ $(,)?) => {
+        $crate::prelude::any_switch_on::<($($marker,)+)>
+    };
+}
+
+/// Represents a tuple of the marker types of [`Switch`], such as `(A, B, C)`.
+///
+/// It's implemented for tuples of up to 8 marker types, and used by
+/// [`all_switches_on`], [`any_switch_on`], and [`wait::switch::all_on`](crate::prelude::wait::switch::all_on).
+pub trait SwitchTuple: Send + Sync + 'static {
+    /// Returns true if all switches are on.
+    ///
+    /// A switch that doesn't exist is regarded as off.
+    fn all_on(world: &World) -> bool;
+
+    /// Returns true if any switch is on.
+    ///
+    /// A switch that doesn't exist is regarded as off.
+    fn any_on(world: &World) -> bool;
+}
+
+macro_rules! impl_switch_tuple {
+    ($($marker: ident),+) => {
+        impl<$($marker,)+> SwitchTuple for ($($marker,)+)
+            where $($marker: Send + Sync + 'static,)+
+        {
+            #[inline]
+            fn all_on(world: &World) -> bool {
+                $(world.get_resource::<Switch<$marker>>().is_some_and(Switch::is_on))&&+
+            }
+
+            #[inline]
+            fn any_on(world: &World) -> bool {
+                $(world.get_resource::<Switch<$marker>>().is_some_and(Switch::is_on))||+
+            }
+        }
+    };
+}
+
+impl_switch_tuple!(M1);
+impl_switch_tuple!(M1, M2);
+impl_switch_tuple!(M1, M2, M3);
+impl_switch_tuple!(M1, M2, M3, M4);
+impl_switch_tuple!(M1, M2, M3, M4, M5);
+impl_switch_tuple!(M1, M2, M3, M4, M5, M6);
+impl_switch_tuple!(M1, M2, M3, M4, M5, M6, M7);
+impl_switch_tuple!(M1, M2, M3, M4, M5, M6, M7, M8);
+
+/// Creates a Condition-satisfying system that returns true if the switch has been on for at least `duration`.
+///
+/// The time is measured from the transition recorded by [`Switch::record_change`],
+/// so the switch is regarded as not satisfying the condition until its transition is recorded.
+///
+/// ## Setup
+///
+/// Only the transitions made by [`once::switch`](crate::prelude::once::switch) are recorded by default.
+/// If the switch is turned on directly, such as by `ResMut<Switch<M>>` or [`SwitchExtension::init_switch`],
+/// add [`SwitchExtension::add_switch_timestamps`], otherwise this condition never returns true.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct ConfirmButtonHeld;
+///
+/// App::new()
+///     .add_switch_timestamps::<ConfirmButtonHeld>()
+///     .add_systems(Update, (|| {
+///         println!("confirmed");
+///     }).run_if(switch_on_for_at_least::<ConfirmButtonHeld>(std::time::Duration::from_secs(2))));
+/// ```
+#[inline]
+pub fn switch_on_for_at_least<M>(duration: Duration) -> impl FnMut(Option<Res<Switch<M>>>, Res<Time>) -> bool + Clone
+    where M: Send + Sync + 'static
+{
+    move |switch: Option<Res<Switch<M>>>, time: Res<Time>| {
+        switch.is_some_and(|s| {
+            s.is_on() && s
+                .time_since_change(time.elapsed())
+                .is_some_and(|since| duration <= since)
+        })
+    }
+}
+
 /// A switch is a structure that represents two states: `on` and `off`.
 ///
 /// This is to solve the problem that systems created from `Reactors`
 /// cannot run except on the main thread.
 ///
+/// The switch can optionally carry a payload of type `T`, such as which level to load,
+/// set by [`Switch::set_on_with`] or [`once::switch::on_with`](crate::prelude::once::switch::on_with).
+///
+/// The switch implements [`Reflect`] if the marker type implements [`TypePath`],
+/// so it can be shown in inspectors by registering it with [`SwitchExtension::register_switch`].
+/// The payload is not reflected.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -94,24 +323,40 @@ pub fn switch_just_turned_off<M>(
 ///         })); 
 ///     });
 /// ```
-#[derive(Debug, Eq, PartialEq)]
-pub struct Switch<M> {
+#[derive(Debug, Eq, PartialEq, Reflect)]
+#[reflect(Resource)]
+#[reflect(where M: Send + Sync + 'static, T: Send + Sync + 'static)]
+pub struct Switch<M, T = ()> {
     is_on: bool,
+    transitions: u64,
+    #[reflect(ignore)]
+    payload: Option<T>,
+    expiration: Option<Timer>,
+    last_change: Option<(u64, Duration)>,
+    #[reflect(ignore)]
     _m: PhantomData<M>,
 }
 
-impl<M> Resource for Switch<M>
-    where M: Send + Sync + 'static
+impl<M, T> Resource for Switch<M, T>
+    where
+        M: Send + Sync + 'static,
+        T: Send + Sync + 'static
 {}
 
-impl<M> Switch<M>
-    where M: Send + Sync + 'static
+impl<M, T> Switch<M, T>
+    where
+        M: Send + Sync + 'static,
+        T: Send + Sync + 'static
 {
     /// Create new Switch with initial status.
     #[inline(always)]
-    pub const fn new(turn_on: bool) -> Switch<M> {
+    pub const fn new(turn_on: bool) -> Switch<M, T> {
         Self {
             is_on: turn_on,
+            transitions: turn_on as u64,
+            payload: None,
+            expiration: None,
+            last_change: None,
             _m: PhantomData,
         }
     }
@@ -138,54 +383,1263 @@ impl<M> Switch<M>
     }
 
     /// Turn on the switch.
+    ///
+    /// This cancels the expiration set by [`Switch::on_for`].
     #[inline(always)]
     pub fn on(&mut self) {
+        self.expiration = None;
         if self.is_off() {
             self.is_on = true;
+            self.transitions += 1;
         }
     }
 
     /// Turn off the switch.
+    ///
+    /// The payload is kept until it is overwritten or taken.
     #[inline(always)]
     pub fn off(&mut self) {
+        self.expiration = None;
         if self.is_on {
             self.is_on = false;
+            self.transitions += 1;
+        }
+    }
+
+    /// Returns the number of times the switch has been turned on or off.
+    ///
+    /// A switch created as on counts as turned on once.
+    /// Consumers can store this value as an acknowledgement,
+    /// and pass it to [`Switch::turned_on_since`] or [`Switch::turned_off_since`] later
+    /// to detect every edge deterministically, however many consumers there are.
+    #[inline(always)]
+    pub const fn transitions(&self) -> u64 {
+        self.transitions
+    }
+
+    /// Returns true if the switch has been turned on since `acknowledged`,
+    /// the value of [`Switch::transitions`] at the time of the acknowledgement.
+    #[inline(always)]
+    pub const fn turned_on_since(&self, acknowledged: u64) -> bool {
+        let edges = self.edges_since(acknowledged);
+        if self.is_on { 1 <= edges } else { 2 <= edges }
+    }
+
+    /// Returns true if the switch has been turned off since `acknowledged`,
+    /// the value of [`Switch::transitions`] at the time of the acknowledgement.
+    #[inline(always)]
+    pub const fn turned_off_since(&self, acknowledged: u64) -> bool {
+        let edges = self.edges_since(acknowledged);
+        if self.is_on { 2 <= edges } else { 1 <= edges }
+    }
+
+    #[inline(always)]
+    const fn edges_since(&self, acknowledged: u64) -> u64 {
+        switch_edges(acknowledged, self.transitions)
+    }
+
+    /// Turn on the switch, and turn it off automatically after `duration` has elapsed.
+    ///
+    /// The expiration is ticked in [`First`] if the switch is registered by [`SwitchExtension::add_switch_timer`]
+    /// or [`once::switch::on_for_duration`](crate::prelude::once::switch::on_for_duration).
+    #[inline(always)]
+    pub fn on_for(&mut self, duration: Duration) {
+        self.on();
+        self.expiration.replace(Timer::new(duration, TimerMode::Once));
+    }
+
+    /// Returns the remaining time until the switch turns off automatically,
+    /// or `None` if the expiration is not set.
+    #[inline(always)]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.expiration.as_ref().map(Timer::remaining)
+    }
+
+    /// Records `elapsed` as the time of the latest transition, if it hasn't been recorded yet.
+    ///
+    /// This is called with [`Time::elapsed`] by the actions in [`once::switch`](crate::prelude::once::switch),
+    /// and by the system added by [`SwitchExtension::add_switch_timestamps`] for the switches changed directly.
+    #[inline]
+    pub fn record_change(&mut self, elapsed: Duration) {
+        if self.last_change.is_none_or(|(transitions, _)| transitions != self.transitions) {
+            self.last_change.replace((self.transitions, elapsed));
+        }
+    }
+
+    /// Returns the [`Time::elapsed`] at which the latest transition was recorded,
+    /// or `None` if it hasn't been recorded yet.
+    #[inline]
+    pub fn changed_at(&self) -> Option<Duration> {
+        self.last_change
+            .filter(|(transitions, _)| *transitions == self.transitions)
+            .map(|(_, elapsed)| elapsed)
+    }
+
+    /// Returns how long the switch has been in its current state,
+    /// where `elapsed` is the current [`Time::elapsed`].
+    ///
+    /// Returns `None` if the latest transition hasn't been recorded yet.
+    #[inline]
+    pub fn time_since_change(&self, elapsed: Duration) -> Option<Duration> {
+        self.changed_at().map(|changed_at| elapsed.saturating_sub(changed_at))
+    }
+
+    /// An alias of [`Switch::time_since_change`].
+    #[inline(always)]
+    pub fn time_in_state(&self, elapsed: Duration) -> Option<Duration> {
+        self.time_since_change(elapsed)
+    }
+
+    /// Toggles the switch, turning it on if it is off and vice versa.
+    #[inline(always)]
+    pub fn toggle(&mut self) {
+        self.set(self.is_off());
+    }
+
+    /// Turn on the switch with the payload.
+    ///
+    /// The payload can be read by the system reacting to the switch,
+    /// for example to know which level to load.
+    #[inline(always)]
+    pub fn set_on_with(&mut self, payload: T) {
+        self.payload.replace(payload);
+        self.on();
+    }
+
+    /// Returns the payload set by [`Switch::set_on_with`].
+    #[inline(always)]
+    pub const fn payload(&self) -> Option<&T> {
+        self.payload.as_ref()
+    }
+
+    /// Takes the payload set by [`Switch::set_on_with`], leaving `None` in its place.
+    #[inline(always)]
+    pub fn take_payload(&mut self) -> Option<T> {
+        self.payload.take()
+    }
+
+    fn set_in(world: &mut World, turn_on: bool) {
+        Self::setup(world, turn_on);
+    }
+
+    pub(crate) fn setup(world: &mut World, turn_on: bool) -> Mut<Switch<M, T>> {
+        let elapsed = world.get_resource::<Time>().map(Time::elapsed);
+        let mut switch = world.get_resource_or_insert_with(Self::default);
+        switch.set(turn_on);
+        if let Some(elapsed) = elapsed {
+            switch.record_change(elapsed);
+        }
+        switch
+    }
+}
+
+impl<M, T> Default for Switch<M, T>
+    where
+        M: Send + Sync + 'static,
+        T: Send + Sync + 'static
+{
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+/// Creates a Condition-satisfying system that returns true if [`Counter<M>`] is at least `n`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Workers;
+///
+/// App::new()
+///     .add_systems(Update, (|| {
+///         println!("all workers done");
+///     }).run_if(counter_at_least::<Workers>(3)));
+/// ```
+#[inline]
+pub fn counter_at_least<M>(n: usize) -> impl FnMut(Option<Res<Counter<M>>>) -> bool + Clone
+    where M: Send + Sync + 'static
+{
+    move |counter: Option<Res<Counter<M>>>| {
+        counter.is_some_and(|counter| n <= counter.get())
+    }
+}
+
+/// A counter is the counting version of [`Switch`], which holds a non-negative count.
+///
+/// It can be used where a binary switch is not enough,
+/// such as waiting until all workers have reported done.
+/// A counter that doesn't exist is regarded as 0.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Workers;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::counter::reaches::<Workers>().with(3)).await;
+/// });
+/// ```
+#[derive(Debug, Eq, PartialEq)]
+pub struct Counter<M> {
+    count: usize,
+    _m: PhantomData<M>,
+}
+
+impl<M> Resource for Counter<M>
+    where M: Send + Sync + 'static
+{}
+
+impl<M> Counter<M>
+    where M: Send + Sync + 'static
+{
+    /// Create new [`Counter`] with initial count.
+    #[inline(always)]
+    pub const fn new(count: usize) -> Counter<M> {
+        Self {
+            count,
+            _m: PhantomData,
         }
     }
 
-    pub(crate) fn setup(world: &mut World, turn_on: bool) -> Mut<Switch<M>> {
-        world.insert_resource(Self::new(turn_on));
-        world.resource_mut::<Switch<M>>()
+    /// Returns the current count.
+    #[inline(always)]
+    pub const fn get(&self) -> usize {
+        self.count
+    }
+
+    /// Sets the count.
+    #[inline(always)]
+    pub fn set(&mut self, count: usize) {
+        self.count = count;
+    }
+
+    /// Increments the count.
+    #[inline(always)]
+    pub fn increment(&mut self) {
+        self.count = self.count.saturating_add(1);
+    }
+
+    /// Decrements the count, saturating at 0.
+    #[inline(always)]
+    pub fn decrement(&mut self) {
+        self.count = self.count.saturating_sub(1);
     }
 }
 
-impl<M> Default for Switch<M>
+impl<M> Default for Counter<M>
     where M: Send + Sync + 'static
 {
     fn default() -> Self {
-        Self::new(false)
+        Self::new(0)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::prelude::Switch;
+/// A thread-safe switch handle, which can be turned on or off from background threads.
+///
+/// It is registered as a resource by [`SwitchExtension::add_atomic_switch`],
+/// and whenever its state changes, the state is mirrored into [`Switch<M>`] once per frame,
+/// so reactors can wait for it with [`wait::switch`](crate::prelude::wait::switch).
+/// Changes made directly to [`Switch<M>`] are not reflected back into this handle.
+pub struct AtomicSwitch<M> {
+    is_on: Arc<AtomicBool>,
+    _m: PhantomData<fn() -> M>,
+}
 
-    struct T;
+impl<M> Resource for AtomicSwitch<M>
+    where M: Send + Sync + 'static
+{}
 
-    #[test]
-    fn off() {
-        let mut s = Switch::<T>::new(true);
-        assert!(s.is_on);
-        s.off();
-        assert!(s.is_off());
+impl<M> Clone for AtomicSwitch<M> {
+    fn clone(&self) -> Self {
+        Self {
+            is_on: self.is_on.clone(),
+            _m: PhantomData,
+        }
     }
+}
 
-    #[test]
-    fn on() {
-        let mut s = Switch::<T>::new(false);
-        assert!(s.is_off());
-        s.on();
-        assert!(s.is_on());
+impl<M> AtomicSwitch<M>
+    where M: Send + Sync + 'static
+{
+    /// Create new [`AtomicSwitch`] with initial status.
+    #[inline]
+    pub fn new(turn_on: bool) -> AtomicSwitch<M> {
+        Self {
+            is_on: Arc::new(AtomicBool::new(turn_on)),
+            _m: PhantomData,
+        }
+    }
+
+    /// Returns true if switch is on.
+    #[inline]
+    pub fn is_on(&self) -> bool {
+        self.is_on.load(Ordering::Acquire)
+    }
+
+    /// Returns true if switch is off.
+    #[inline]
+    pub fn is_off(&self) -> bool {
+        !self.is_on()
+    }
+
+    /// Sets turn on or off.
+    #[inline]
+    pub fn set(&self, turn_on: bool) {
+        self.is_on.store(turn_on, Ordering::Release);
     }
-}
\ No newline at end of file
+
+    /// Turn on the switch.
+    #[inline]
+    pub fn on(&self) {
+        self.set(true);
+    }
+
+    /// Turn off the switch.
+    #[inline]
+    pub fn off(&self) {
+        self.set(false);
+    }
+}
+
+fn mirror_atomic_switch<M>(
+    atomic: Res<AtomicSwitch<M>>,
+    switch: Option<ResMut<Switch<M>>>,
+    mut mirrored: Local<Option<bool>>,
+    mut commands: Commands,
+)
+    where M: Send + Sync + 'static
+{
+    let is_on = atomic.is_on();
+    if mirrored.is_some_and(|mirrored| mirrored == is_on) {
+        return;
+    }
+    mirrored.replace(is_on);
+    if let Some(mut switch) = switch {
+        switch.set(is_on);
+    } else {
+        commands.insert_resource(Switch::<M>::new(is_on));
+    }
+}
+
+/// Creates a Condition-satisfying system that returns true if [`SwitchComponent<M>`] of the entity is on.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Open;
+///
+/// fn setup(mut commands: Commands){
+///     let door = commands.spawn(SwitchComponent::<Open>::new(false)).id();
+///     commands.spawn(Reactor::schedule(move |task| async move{
+///         task.will(Update, once::switch::on_for::<Open>().with(door)).await;
+///     }));
+/// }
+///
+/// App::new()
+///     .add_systems(Startup, setup);
+/// ```
+#[inline]
+pub fn switch_is_on_for<M>(entity: Entity) -> impl FnMut(Query<&SwitchComponent<M>>) -> bool + Clone
+    where M: Send + Sync + 'static
+{
+    move |switches: Query<&SwitchComponent<M>>| {
+        switches.get(entity).is_ok_and(|s| s.is_on())
+    }
+}
+
+/// Creates a Condition-satisfying system that returns true if [`SwitchComponent<M>`] of the entity is off.
+#[inline]
+pub fn switch_is_off_for<M>(entity: Entity) -> impl FnMut(Query<&SwitchComponent<M>>) -> bool + Clone
+    where M: Send + Sync + 'static
+{
+    move |switches: Query<&SwitchComponent<M>>| {
+        switches.get(entity).is_ok_and(|s| s.is_off())
+    }
+}
+
+/// The component version of [`Switch`], which represents `on` and `off` per entity.
+///
+/// This allows per-entity reactors, such as for each enemy or door,
+/// to share the marker type `M` instead of defining a marker type per instance.
+/// To find the entities whose switch is on, query `&SwitchComponent<M>` and filter by [`SwitchComponent::is_on`].
+#[derive(Component, Debug, Eq, PartialEq)]
+pub struct SwitchComponent<M> {
+    is_on: bool,
+    _m: PhantomData<M>,
+}
+
+impl<M> SwitchComponent<M>
+    where M: Send + Sync + 'static
+{
+    /// Create new [`SwitchComponent`] with initial status.
+    #[inline(always)]
+    pub const fn new(turn_on: bool) -> SwitchComponent<M> {
+        Self {
+            is_on: turn_on,
+            _m: PhantomData,
+        }
+    }
+
+    /// Returns true if switch is on.
+    #[inline(always)]
+    pub const fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    /// Returns true if switch is off.
+    #[inline(always)]
+    pub const fn is_off(&self) -> bool {
+        !self.is_on
+    }
+
+    /// Sets turn on or off.
+    #[inline(always)]
+    pub fn set(&mut self, turn_on: bool) {
+        self.is_on = turn_on;
+    }
+
+    /// Turn on the switch.
+    #[inline(always)]
+    pub fn on(&mut self) {
+        self.set(true);
+    }
+
+    /// Turn off the switch.
+    #[inline(always)]
+    pub fn off(&mut self) {
+        self.set(false);
+    }
+
+    /// Toggles the switch, turning it on if it is off and vice versa.
+    #[inline(always)]
+    pub fn toggle(&mut self) {
+        self.set(self.is_off());
+    }
+}
+
+impl<M> Default for SwitchComponent<M>
+    where M: Send + Sync + 'static
+{
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+/// The event sent on every transition of [`Switch<M>`] registered by [`SwitchExtension::add_switch_event`].
+///
+/// Unlike [`switch_just_turned_on`] and [`switch_just_turned_off`], which hold their state in [`Local`],
+/// every system reading this event observes the same transitions.
+///
+/// The transitions are detected once per frame in [`Last`] based on [`Switch::transitions`],
+/// and one event is sent per edge, so the switch turned on and off again within a single frame
+/// is reported as two events.
+#[derive(Event)]
+pub struct SwitchChanged<M> {
+    /// True if the switch has been turned on, false if it has been turned off.
+    pub turned_on: bool,
+    _m: PhantomData<M>,
+}
+
+/// Provides the registration of [`Switch`] related events and systems.
+pub trait SwitchExtension {
+    /// Registers [`Switch<M>`] in the type registry so that it can be shown in inspectors.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(TypePath)]
+    /// struct Door;
+    ///
+    /// App::new()
+    ///     .register_switch::<Door>();
+    /// ```
+    fn register_switch<M>(&mut self) -> &mut Self
+        where M: TypePath + Send + Sync + 'static;
+
+    /// Adds [`SwitchChanged<M>`] and the system that sends it when [`Switch<M>`] is turned on or off.
+    ///
+    /// A switch that doesn't exist is regarded as off.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct Door;
+    ///
+    /// App::new()
+    ///     .add_switch_event::<Door>()
+    ///     .add_systems(Update, |mut er: EventReader<SwitchChanged<Door>>|{
+    ///         for event in er.read() {
+    ///             println!("door opened: {}", event.turned_on);
+    ///         }
+    ///     });
+    /// ```
+    fn add_switch_event<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static;
+
+    /// Registers [`Switch<M>`] so that the expiration set by [`Switch::on_for`] is ticked in [`First`].
+    ///
+    /// It is also registered automatically when [`once::switch::on_for_duration`](crate::prelude::once::switch::on_for_duration) runs.
+    fn add_switch_timer<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static;
+
+    /// Inserts [`AtomicSwitch<M>`] if it doesn't exist yet, and adds the system that mirrors it
+    /// into [`Switch<M>`] in [`First`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct Downloaded;
+    ///
+    /// App::new()
+    ///     .add_atomic_switch::<Downloaded>()
+    ///     .add_systems(Startup, |switch: Res<AtomicSwitch<Downloaded>>, mut commands: Commands|{
+    ///         let switch = switch.clone();
+    ///         std::thread::spawn(move ||{
+    ///             // download
+    ///             switch.on();
+    ///         });
+    ///         commands.spawn(Reactor::schedule(|task| async move{
+    ///             task.will(Update, wait::switch::on::<Downloaded>()).await;
+    ///         }));
+    ///     });
+    /// ```
+    fn add_atomic_switch<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static;
+
+    /// Inserts [`Switch<M>`] up front with the given initial state.
+    ///
+    /// Once inserted, systems can read the switch as `Res<Switch<M>>` instead of `Option<Res<Switch<M>>>`.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct Lights;
+    ///
+    /// App::new()
+    ///     .init_switch::<Lights>(true)
+    ///     .add_systems(Update, |switch: Res<Switch<Lights>>|{
+    ///         println!("lights on: {}", switch.is_on());
+    ///     });
+    /// ```
+    fn init_switch<M>(&mut self, initial_on: bool) -> &mut Self
+        where M: Send + Sync + 'static;
+
+    /// Registers [`Switch<M>`] under `group` in [`SwitchGroups`].
+    ///
+    /// A switch can belong to several groups.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct Attacking;
+    /// struct Guarding;
+    ///
+    /// App::new()
+    ///     .add_switch_to_group::<Attacking>("combat")
+    ///     .add_switch_to_group::<Guarding>("combat");
+    /// ```
+    fn add_switch_to_group<M>(&mut self, group: impl Into<String>) -> &mut Self
+        where M: Send + Sync + 'static;
+
+    /// Adds the system that records [`Time::elapsed`] in [`Last`] when [`Switch<M>`] is turned on or off directly,
+    /// which is required by [`Switch::time_since_change`] and [`switch_on_for_at_least`].
+    ///
+    /// The transitions made by [`once::switch`](crate::prelude::once::switch) are recorded without this.
+    fn add_switch_timestamps<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static;
+}
+
+impl SwitchExtension for App {
+    #[inline]
+    fn register_switch<M>(&mut self) -> &mut Self
+        where M: TypePath + Send + Sync + 'static
+    {
+        self.register_type::<Switch<M>>()
+    }
+
+    fn add_switch_event<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static
+    {
+        self
+            .add_event::<SwitchChanged<M>>()
+            .add_systems(Last, send_switch_changed::<M>)
+    }
+
+    fn add_switch_timer<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static
+    {
+        register_switch_timer::<M>(self.world_mut());
+        self
+    }
+
+    fn add_atomic_switch<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static
+    {
+        if !self.world().contains_resource::<AtomicSwitch<M>>() {
+            self
+                .insert_resource(AtomicSwitch::<M>::new(false))
+                .add_systems(First, mirror_atomic_switch::<M>);
+        }
+        self
+    }
+
+    #[inline]
+    fn init_switch<M>(&mut self, initial_on: bool) -> &mut Self
+        where M: Send + Sync + 'static
+    {
+        self.insert_resource(Switch::<M>::new(initial_on))
+    }
+
+    fn add_switch_to_group<M>(&mut self, group: impl Into<String>) -> &mut Self
+        where M: Send + Sync + 'static
+    {
+        self
+            .world_mut()
+            .get_resource_or_insert_with(SwitchGroups::default)
+            .register::<M>(group);
+        self
+    }
+
+    #[inline]
+    fn add_switch_timestamps<M>(&mut self) -> &mut Self
+        where M: Send + Sync + 'static
+    {
+        self.add_systems(Last, record_switch_change::<M>)
+    }
+}
+
+fn record_switch_change<M>(
+    switch: Option<ResMut<Switch<M>>>,
+    time: Res<Time>,
+)
+    where M: Send + Sync + 'static
+{
+    if let Some(mut switch) = switch {
+        switch.bypass_change_detection().record_change(time.elapsed());
+    }
+}
+
+type SetSwitch = fn(&mut World, bool);
+
+/// The registry of switches grouped by labels, such as `"combat"`.
+///
+/// Switches are registered by [`SwitchExtension::add_switch_to_group`] or [`SwitchGroups::register`],
+/// and turned on or off together by [`SwitchWorldExtension`] or
+/// [`once::switch::group_on`](crate::prelude::once::switch::group_on) and [`once::switch::group_off`](crate::prelude::once::switch::group_off).
+#[derive(Resource, Default)]
+pub struct SwitchGroups(HashMap<String, Vec<(TypeId, SetSwitch)>>);
+
+impl SwitchGroups {
+    /// Registers [`Switch<M>`] under `group`.
+    ///
+    /// Registering the same switch to the same group twice has no effect.
+    pub fn register<M>(&mut self, group: impl Into<String>)
+        where M: Send + Sync + 'static
+    {
+        let switches = self.0.entry(group.into()).or_default();
+        if !switches.iter().any(|(id, _)| *id == TypeId::of::<M>()) {
+            switches.push((TypeId::of::<M>(), Switch::<M>::set_in));
+        }
+    }
+
+    /// Returns true if [`Switch<M>`] is registered under `group`.
+    pub fn contains<M>(&self, group: &str) -> bool
+        where M: 'static
+    {
+        self.0
+            .get(group)
+            .is_some_and(|switches| switches.iter().any(|(id, _)| *id == TypeId::of::<M>()))
+    }
+
+    fn setters(&self, group: Option<&str>) -> Vec<SetSwitch> {
+        let mut setters: Vec<(TypeId, SetSwitch)> = Vec::new();
+        let groups = self.0
+            .iter()
+            .filter(|(label, _)| group.is_none_or(|group| group == label.as_str()));
+        for (_, switches) in groups {
+            for (id, set) in switches {
+                if !setters.iter().any(|(registered, _)| registered == id) {
+                    setters.push((*id, *set));
+                }
+            }
+        }
+        setters.into_iter().map(|(_, set)| set).collect()
+    }
+}
+
+/// Provides the methods to turn many switches on or off at once through [`SwitchGroups`].
+pub trait SwitchWorldExtension {
+    /// Turns on all switches registered under `group`.
+    fn switch_group_on(&mut self, group: &str);
+
+    /// Turns off all switches registered under `group`.
+    fn switch_group_off(&mut self, group: &str);
+
+    /// Turns off all switches registered in any group.
+    ///
+    /// This is useful to clear the state of a scene on transitions.
+    fn reset_all_switches(&mut self);
+}
+
+impl SwitchWorldExtension for World {
+    #[inline]
+    fn switch_group_on(&mut self, group: &str) {
+        set_switches(self, Some(group), true);
+    }
+
+    #[inline]
+    fn switch_group_off(&mut self, group: &str) {
+        set_switches(self, Some(group), false);
+    }
+
+    #[inline]
+    fn reset_all_switches(&mut self) {
+        set_switches(self, None, false);
+    }
+}
+
+fn set_switches(world: &mut World, group: Option<&str>, turn_on: bool) {
+    let Some(setters) = world
+        .get_resource::<SwitchGroups>()
+        .map(|groups| groups.setters(group)) else {
+        return;
+    };
+    for set in setters {
+        set(world, turn_on);
+    }
+}
+
+type TickSwitchTimer = fn(&mut World, Duration);
+
+/// The registry of the switches whose expiration set by [`Switch::on_for`] is ticked
+/// by [`tick_switch_timers`] in [`First`].
+#[derive(Resource, Default)]
+pub(crate) struct SwitchTimers(Vec<(TypeId, TickSwitchTimer)>);
+
+pub(crate) fn register_switch_timer<M>(world: &mut World)
+    where M: Send + Sync + 'static
+{
+    let mut timers = world.get_resource_or_insert_with(SwitchTimers::default);
+    if !timers.0.iter().any(|(id, _)| *id == TypeId::of::<M>()) {
+        timers.0.push((TypeId::of::<M>(), tick_switch_timer::<M>));
+    }
+}
+
+pub(crate) fn tick_switch_timers(world: &mut World) {
+    let Some(delta) = world.get_resource::<Time>().map(Time::delta) else {
+        return;
+    };
+    let Some(ticks) = world
+        .get_resource::<SwitchTimers>()
+        .map(|timers| timers.0.iter().map(|(_, tick)| *tick).collect::<Vec<_>>()) else {
+        return;
+    };
+    for tick in ticks {
+        tick(world, delta);
+    }
+}
+
+fn tick_switch_timer<M>(world: &mut World, delta: Duration)
+    where M: Send + Sync + 'static
+{
+    let Some(mut switch) = world.get_resource_mut::<Switch<M>>() else {
+        return;
+    };
+    let expired = switch
+        .bypass_change_detection()
+        .expiration
+        .as_mut()
+        .is_some_and(|timer| timer.tick(delta).finished());
+    if expired {
+        switch.off();
+    }
+}
+
+fn send_switch_changed<M>(
+    switch: Option<Res<Switch<M>>>,
+    mut acknowledged: Local<(u64, bool)>,
+    mut ew: EventWriter<SwitchChanged<M>>,
+)
+    where M: Send + Sync + 'static
+{
+    let (transitions, is_on) = switch
+        .map(|s| (s.transitions(), s.is_on()))
+        .unwrap_or_default();
+    let (acknowledged_transitions, was_on) = *acknowledged;
+    let mut edges = switch_edges(acknowledged_transitions, transitions);
+    if edges == 0 && was_on != is_on {
+        // The switch has been removed or replaced.
+        edges = 1;
+    }
+    // The edges alternate, and the last one leads to the current state.
+    for i in (0..edges).rev() {
+        ew.send(SwitchChanged {
+            turned_on: is_on == (i % 2 == 0),
+            _m: PhantomData,
+        });
+    }
+    *acknowledged = (transitions, is_on);
+}
+
+#[inline]
+const fn switch_edges(acknowledged: u64, transitions: u64) -> u64 {
+    if acknowledged <= transitions {
+        transitions - acknowledged
+    } else {
+        // The switch has been replaced with a new one.
+        transitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{all_switches_on, any_switch_on, counter_at_least, switch_is_on_for, switch_on_for_at_least, switch_just_turned_off_ack, switch_just_turned_on_ack, AtomicSwitch, Counter, Pipe, Reactor, Switch, SwitchChanged, SwitchComponent, SwitchExtension, SwitchGroups, SwitchWorldExtension, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{App, Startup, Update};
+    use bevy::ecs::event::EventCursor;
+    use bevy::prelude::{Commands, Events, In, IntoSystemConfigs, ResMut};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use bevy::reflect::TypePath;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    struct T;
+
+    #[test]
+    fn off() {
+        let mut s = Switch::<T>::new(true);
+        assert!(s.is_on);
+        s.off();
+        assert!(s.is_off());
+    }
+
+    #[test]
+    fn on() {
+        let mut s = Switch::<T>::new(false);
+        assert!(s.is_off());
+        s.on();
+        assert!(s.is_on());
+    }
+
+    #[test]
+    fn set_on_with_payload() {
+        let mut s = Switch::<T, usize>::new(false);
+        s.set_on_with(3);
+        assert!(s.is_on());
+        assert_eq!(s.payload(), Some(&3));
+        s.off();
+        assert_eq!(s.take_payload(), Some(3));
+        assert_eq!(s.payload(), None);
+    }
+
+    #[test]
+    fn on_for_is_canceled_by_off() {
+        let mut s = Switch::<T>::new(false);
+        s.on_for(Duration::from_secs(1));
+        assert!(s.is_on());
+        assert_eq!(s.remaining(), Some(Duration::from_secs(1)));
+        s.off();
+        assert_eq!(s.remaining(), None);
+    }
+
+    #[test]
+    fn toggle() {
+        let mut s = Switch::<T>::new(false);
+        s.toggle();
+        assert!(s.is_on());
+        s.toggle();
+        assert!(s.is_off());
+    }
+
+    fn read_changes(app: &App, cursor: &mut EventCursor<SwitchChanged<T>>) -> Vec<bool> {
+        cursor
+            .read(app.world().resource::<Events<SwitchChanged<T>>>())
+            .map(|event| event.turned_on)
+            .collect()
+    }
+
+    #[test]
+    fn send_switch_changed_on_transitions() {
+        let mut app = test_app();
+        app.add_switch_event::<T>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::switch::on::<T>()).await;
+                task.will(Update, once::switch::on::<T>()).await;
+                task.will(Update, once::switch::off::<T>()).await;
+            }));
+        });
+        let mut cursor = app.world().resource::<Events<SwitchChanged<T>>>().get_cursor();
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), vec![true]);
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), Vec::<bool>::new());
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), vec![false]);
+    }
+
+    #[test]
+    fn send_switch_changed_per_edge_within_frame() {
+        let mut app = test_app();
+        app.add_switch_event::<T>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::switch::on::<T>()
+                    .then(once::switch::off::<T>())
+                    .then(once::switch::on::<T>()),
+                ).await;
+                task.will(Update, once::switch::off::<T>()
+                    .then(once::switch::on::<T>())
+                    .then(once::switch::off::<T>()),
+                ).await;
+            }));
+        });
+        let mut cursor = app.world().resource::<Events<SwitchChanged<T>>>().get_cursor();
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), vec![true, false, true]);
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), vec![false, true, false]);
+    }
+
+    #[test]
+    fn send_switch_changed_when_removed() {
+        let mut app = test_app();
+        app.add_switch_event::<T>();
+        app.insert_resource(Switch::<T>::new(true));
+        let mut cursor = app.world().resource::<Events<SwitchChanged<T>>>().get_cursor();
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), vec![true]);
+        app.world_mut().remove_resource::<Switch<T>>();
+        app.update();
+        assert_eq!(read_changes(&app, &mut cursor), vec![false]);
+    }
+
+    #[test]
+    fn switch_is_on_for_entity() {
+        let mut app = test_app();
+        let on = app.world_mut().spawn(SwitchComponent::<T>::new(true)).id();
+        let off = app.world_mut().spawn(SwitchComponent::<T>::new(false)).id();
+        app.add_systems(Update, (|mut b: ResMut<Bool>| {
+            **b = true;
+        }).run_if(switch_is_on_for::<T>(off)));
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.world_mut().get_mut::<SwitchComponent<T>>(off).unwrap().toggle();
+        app.update();
+        assert!(app.is_bool_true());
+        assert!(app.world().get::<SwitchComponent<T>>(on).unwrap().is_on());
+    }
+
+    #[test]
+    fn mirror_atomic_switch_from_thread() {
+        let mut app = test_app();
+        app.add_atomic_switch::<T>();
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_off());
+
+        let atomic = app.world().resource::<AtomicSwitch<T>>().clone();
+        std::thread::spawn(move || atomic.on()).join().unwrap();
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_on());
+
+        app.world_mut().resource_mut::<Switch<T>>().off();
+        app.update();
+        assert!(app.world().resource::<Switch<T>>().is_off());
+    }
+
+    #[test]
+    fn counter_saturates_at_zero() {
+        let mut c = Counter::<T>::new(1);
+        c.decrement();
+        c.decrement();
+        assert_eq!(c.get(), 0);
+        c.increment();
+        assert_eq!(c.get(), 1);
+    }
+
+    #[test]
+    fn counter_at_least_n() {
+        let mut app = test_app();
+        app.insert_resource(Counter::<T>::new(1));
+        app.add_systems(Update, (|mut b: ResMut<Bool>| {
+            **b = true;
+        }).run_if(counter_at_least::<T>(2)));
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.world_mut().resource_mut::<Counter<T>>().increment();
+        app.update();
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn detect_fast_flips_by_transitions() {
+        let mut s = Switch::<T>::new(false);
+        let acknowledged = s.transitions();
+        s.on();
+        s.off();
+        assert!(s.turned_on_since(acknowledged));
+        assert!(s.turned_off_since(acknowledged));
+        let acknowledged = s.transitions();
+        assert!(!s.turned_on_since(acknowledged));
+        assert!(!s.turned_off_since(acknowledged));
+    }
+
+    #[test]
+    fn ack_conditions_observed_by_multiple_systems() {
+        let mut app = test_app();
+        app.insert_resource(Switch::<T>::new(false));
+        app.add_systems(Update, (
+            (|mut count: ResMut<Count>| count.increment()).run_if(switch_just_turned_on_ack::<T>),
+            (|mut count: ResMut<Count>| count.increment()).run_if(switch_just_turned_on_ack::<T>),
+            (|mut b: ResMut<Bool>| **b = true).run_if(switch_just_turned_off_ack::<T>),
+        ));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        {
+            let mut switch = app.world_mut().resource_mut::<Switch<T>>();
+            switch.on();
+            switch.off();
+        }
+        app.update();
+        app.assert_resource_eq(Count(2));
+        assert!(app.is_bool_true());
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn ack_condition_reports_switch_created_as_on() {
+        let mut app = test_app();
+        app.insert_resource(Switch::<T>::new(true));
+        app.add_systems(Update, (
+            (|mut count: ResMut<Count>| count.increment()).run_if(switch_just_turned_on_ack::<T>),
+            (|mut b: ResMut<Bool>| **b = true).run_if(switch_just_turned_off_ack::<T>),
+        ));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        assert!(app.is_bool_false());
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn init_switch_with_initial_state() {
+        let mut app = test_app();
+        app.init_switch::<T>(true);
+        assert!(app.world().resource::<Switch<T>>().is_on());
+
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::switch::off::<T>().then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().resource_mut::<Switch<T>>().off();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn reflect_switch() {
+        use bevy::reflect::{PartialReflect, Struct};
+
+        #[derive(TypePath)]
+        struct R;
+
+        let switch = Switch::<R>::new(true);
+        let reflected = switch.reflect_ref().as_struct().unwrap();
+        assert_eq!(reflected.field("is_on").and_then(|f| f.try_downcast_ref::<bool>()), Some(&true));
+    }
+
+    #[test]
+    fn register_switch() {
+        #[derive(TypePath)]
+        struct R;
+
+        let mut app = test_app();
+        app.register_switch::<R>();
+        let registry = app.world().resource::<bevy::prelude::AppTypeRegistry>().read();
+        assert!(registry.contains(std::any::TypeId::of::<Switch<R>>()));
+    }
+
+    #[test]
+    fn switch_groups() {
+        struct A;
+        struct B;
+        struct C;
+
+        let mut app = test_app();
+        app
+            .add_switch_to_group::<A>("combat")
+            .add_switch_to_group::<B>("combat")
+            .add_switch_to_group::<B>("ui")
+            .add_switch_to_group::<C>("ui");
+        assert!(app.world().resource::<SwitchGroups>().contains::<B>("ui"));
+        assert!(!app.world().resource::<SwitchGroups>().contains::<A>("ui"));
+
+        app.world_mut().switch_group_on("combat");
+        assert!(app.world().resource::<Switch<A>>().is_on());
+        assert!(app.world().resource::<Switch<B>>().is_on());
+        assert!(!app.world().contains_resource::<Switch<C>>());
+
+        app.world_mut().switch_group_on("ui");
+        app.world_mut().switch_group_off("combat");
+        assert!(app.world().resource::<Switch<A>>().is_off());
+        assert!(app.world().resource::<Switch<B>>().is_off());
+        assert!(app.world().resource::<Switch<C>>().is_on());
+
+        app.world_mut().reset_all_switches();
+        assert!(app.world().resource::<Switch<C>>().is_off());
+    }
+
+    #[test]
+    fn time_since_change() {
+        let mut s = Switch::<T>::new(false);
+        assert_eq!(s.time_since_change(Duration::from_secs(1)), None);
+        s.on();
+        s.record_change(Duration::from_secs(1));
+        s.record_change(Duration::from_secs(2));
+        assert_eq!(s.changed_at(), Some(Duration::from_secs(1)));
+        assert_eq!(s.time_since_change(Duration::from_secs(3)), Some(Duration::from_secs(2)));
+        assert_eq!(s.time_in_state(Duration::from_secs(3)), Some(Duration::from_secs(2)));
+        s.off();
+        assert_eq!(s.changed_at(), None);
+    }
+
+    #[test]
+    fn switch_on_for_at_least_after_once_switch_on() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Update, (|mut count: ResMut<Count>| count.increment())
+            .run_if(switch_on_for_at_least::<T>(Duration::from_millis(200))));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::switch::on::<T>()).await;
+            }));
+        });
+
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn record_switch_changed_directly() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_switch_timestamps::<T>();
+        app.insert_resource(Switch::<T>::new(false));
+        app.update();
+        app.update();
+        app.world_mut().resource_mut::<Switch<T>>().on();
+        app.update();
+        assert_eq!(app.world().resource::<Switch<T>>().changed_at(), Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn all_and_any_switches_on() {
+        struct A;
+        struct B;
+
+        let mut app = test_app();
+        app.add_systems(Update, (
+            (|mut count: ResMut<Count>| count.increment()).run_if(all_switches_on::<(T, A, B)>),
+            (|mut b: ResMut<Bool>| **b = true).run_if(any_switch_on::<(T, A)>),
+        ));
+        app.insert_resource(Switch::<A>::new(true));
+        app.update();
+        app.assert_resource_eq(Count(0));
+        assert!(app.is_bool_true());
+
+        app.insert_resource(Switch::<T>::new(true));
+        app.insert_resource(Switch::<B>::new(true));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn all_switches_on_macro() {
+        struct A;
+
+        let mut app = test_app();
+        app.add_systems(Update, (|mut count: ResMut<Count>| count.increment())
+            .run_if(crate::all_switches_on!((T, A))));
+        app.insert_resource(Switch::<T>::new(true));
+        app.insert_resource(Switch::<A>::new(true));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_all_switches_on() {
+        struct A;
+
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::switch::all_on::<(T, A)>().then(increment_count())).await;
+            }));
+        });
+        app.insert_resource(Switch::<T>::new(true));
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.insert_resource(Switch::<A>::new(true));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_switch_on_with_payload() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::switch::on_with::<T, usize>()
+                    .pipe(once::run(|In(payload): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = payload;
+                    })),
+                ).await;
+            }));
+        });
+        app.insert_resource(Switch::<T, usize>::new(true));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().resource_mut::<Switch<T, usize>>().set_on_with(3);
+        app.update();
+        app.assert_resource_eq(Count(3));
+        assert_eq!(app.world().resource::<Switch<T, usize>>().payload(), Some(&3));
+    }
+}
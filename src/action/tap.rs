@@ -0,0 +1,161 @@
+//! Provides the mechanism to perform side effects on the output of an action.
+//!
+//! trait
+//!
+//! - [`Tap`]
+
+use crate::action::remake::Remake;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+
+/// Performs side effects on the output of an action, passing the output through unchanged.
+///
+/// Unlike [`Inspect`](crate::prelude::Inspect), which passes a clone of the value to another action,
+/// this takes a plain closure and does not require the output to implement [`Clone`].
+pub trait Tap<I1, O1, ActionOrSeed> {
+    /// Calls `f` with a reference to the output when the action completes,
+    /// and then outputs it as is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, once::run(|| 3)
+    ///         .tap(|num| println!("output: {num}"))
+    ///         .pipe(once::run(|In(num): In<usize>|{
+    ///             assert_eq!(num, 3);
+    ///         }))
+    ///     ).await;
+    /// });
+    /// ```
+    fn tap(self, f: impl FnOnce(&O1) + Send + Sync + 'static) -> ActionOrSeed;
+
+    /// Calls `f` with a reference to the output and the [`World`] when the action completes,
+    /// and then outputs it as is.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Score(usize);
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, once::run(|| 3)
+    ///         .tap_world(|num, world| {
+    ///             world.resource_mut::<Score>().0 += *num;
+    ///         })
+    ///         .pipe(once::run(|In(num): In<usize>|{
+    ///             assert_eq!(num, 3);
+    ///         }))
+    ///     ).await;
+    /// });
+    /// ```
+    fn tap_world(self, f: impl FnOnce(&O1, &mut World) + Send + Sync + 'static) -> ActionOrSeed;
+}
+
+impl<I1, O1, A, Re> Tap<I1, O1, A> for Re
+where
+    I1: 'static,
+    O1: 'static,
+    Re: Remake<I1, O1, O1, A> + 'static,
+{
+    #[inline]
+    fn tap(self, f: impl FnOnce(&O1) + Send + Sync + 'static) -> A {
+        self.tap_world(move |o, _| f(o))
+    }
+
+    #[inline]
+    fn tap_world(self, f: impl FnOnce(&O1, &mut World) + Send + Sync + 'static) -> A {
+        self.remake(|r1, o1, output| TapRunner {
+            r1,
+            o1,
+            output,
+            tap: Some(f),
+        })
+    }
+}
+
+struct TapRunner<O, F> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    output: Output<O>,
+    tap: Option<F>,
+}
+
+impl<O, F> Runner for TapRunner<O, F>
+where
+    F: FnOnce(&O, &mut World) + 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.r1.run(world, token) {
+            RunnerIs::Canceled => RunnerIs::Canceled,
+            RunnerIs::Running => RunnerIs::Running,
+            RunnerIs::Completed => {
+                let o = self.o1.take().expect("An output value hasn't been set!!!");
+                let tap = self.tap.take().expect("Failed to take the tap function");
+                tap(&o, world);
+                self.output.set(o);
+                RunnerIs::Completed
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::action::tap::Tap;
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn tap_passes_output_through() {
+        let mut app = test_app();
+        let tapped = Arc::new(AtomicUsize::new(0));
+        let t = tapped.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let t = t.clone();
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| 3)
+                    .tap(move |num| t.store(*num, Ordering::Relaxed))
+                    .pipe(once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = num;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert_eq!(tapped.load(Ordering::Relaxed), 3);
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn tap_world_accesses_world() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let num = task.will(Update, once::run(|| 2)
+                    .tap_world(|num, world| {
+                        world.resource_mut::<Count>().0 += *num;
+                    }),
+                ).await;
+                assert_eq!(num, 2);
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+}
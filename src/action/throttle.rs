@@ -0,0 +1,195 @@
+//! Provides the mechanism to limit how frequently an action outputs.
+//!
+//! trait
+//!
+//! - [`Throttle`]
+
+use crate::action::remake::Remake;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::{TimerMode, World};
+use bevy::time::{Time, Timer};
+use std::time::Duration;
+
+/// Limits how frequently an action outputs.
+///
+/// Combined with [`repeat`](crate::prelude::repeat), the repeated action
+/// produces at most one output per the specified interval.
+pub trait Throttle<I1, O1, ActionOrSeed> {
+    /// Creates an action that holds the output of this action
+    /// until `duration` has elapsed since it started.
+    ///
+    /// If this action takes longer than `duration`, the output is not delayed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Event, Clone)]
+    /// struct RequestSave;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     // Saves at most once every 10 seconds.
+    ///     task.will(Update, repeat::forever(|| {
+    ///         wait::event::comes::<RequestSave>()
+    ///             .then(once::run(|| println!("saving...")))
+    ///             .throttle(Duration::from_secs(10))
+    ///     })).await;
+    /// });
+    /// ```
+    fn throttle(self, duration: Duration) -> ActionOrSeed;
+
+    /// Creates an action that holds the output of this action
+    /// until it has been run for `frames` frames.
+    ///
+    /// If this action takes longer than `frames`, the output is not delayed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     // Sends at most once every 30 frames.
+    ///     task.will(Update, repeat::forever(|| {
+    ///         once::run(|| println!("sync"))
+    ///             .throttle_frames(30)
+    ///     })).await;
+    /// });
+    /// ```
+    fn throttle_frames(self, frames: usize) -> ActionOrSeed;
+}
+
+impl<I1, O1, A, Re> Throttle<I1, O1, A> for Re
+where
+    I1: 'static,
+    O1: 'static,
+    Re: Remake<I1, O1, O1, A> + 'static,
+{
+    #[inline]
+    fn throttle(self, duration: Duration) -> A {
+        self.remake(move |r1, o1, output| ThrottleRunner {
+            r1: Some(r1),
+            o1,
+            output,
+            interval: Interval::Time(Timer::new(duration, TimerMode::Once)),
+        })
+    }
+
+    #[inline]
+    fn throttle_frames(self, frames: usize) -> A {
+        self.remake(move |r1, o1, output| ThrottleRunner {
+            r1: Some(r1),
+            o1,
+            output,
+            interval: Interval::Frames(frames),
+        })
+    }
+}
+
+enum Interval {
+    Time(Timer),
+    Frames(usize),
+}
+
+impl Interval {
+    fn tick(&mut self, world: &World) -> bool {
+        match self {
+            Interval::Time(timer) => timer.tick(world.resource::<Time>().delta()).finished(),
+            Interval::Frames(remaining) => {
+                *remaining = remaining.saturating_sub(1);
+                *remaining == 0
+            }
+        }
+    }
+}
+
+struct ThrottleRunner<O> {
+    r1: Option<BoxedRunner>,
+    o1: Output<O>,
+    output: Output<O>,
+    interval: Interval,
+}
+
+impl<O> Runner for ThrottleRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if let Some(r1) = self.r1.as_mut() {
+            match r1.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => {}
+                RunnerIs::Completed => {
+                    self.r1 = None;
+                }
+            }
+        }
+
+        let elapsed = self.interval.tick(world);
+        if elapsed && self.r1.is_none() {
+            let o = self.o1.take().expect("An output value hasn't been set!!!");
+            self.output.set(o);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::throttle::Throttle;
+    use crate::prelude::{repeat, Reactor};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn throttle_frames_with_repeat() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::forever(|| increment_count().throttle_frames(3))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn throttle_time_holds_output() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, repeat::count(2, || increment_count().throttle(Duration::from_secs(1)))).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        // The first action has been run, but its output is held.
+        app.assert_resource_eq(Count(1));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+        for _ in 0..6 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
+}
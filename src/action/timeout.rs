@@ -0,0 +1,162 @@
+//! Provides the mechanism to bound the execution time of an action.
+//!
+//! trait
+//!
+//! - [`Timeout`]
+//!
+//! struct
+//!
+//! - [`Elapsed`]
+
+use crate::action::remake::Remake;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::{TimerMode, World};
+use bevy::time::{Time, Timer};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+/// The error returned by [`Timeout::timeout`] if the action didn't complete in time.
+#[derive(Default, Debug, Eq, PartialEq, Copy, Clone, Hash, Ord, PartialOrd)]
+pub struct Elapsed;
+
+impl Display for Elapsed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the action has timed out")
+    }
+}
+
+impl Error for Elapsed {}
+
+/// Bounds the time an action is allowed to run.
+pub trait Timeout<I1, O1, ActionOrSeed> {
+    /// Creates an action that outputs `Ok` with the output of the original action
+    /// if it completes before `duration` elapses, otherwise `Err(`[`Elapsed`]`)`.
+    ///
+    /// The time is measured by [`Time`] of the schedule in which the action is running,
+    /// and the original action is dropped when it times out.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let result = task.will(Update, wait::input::just_pressed().with(KeyCode::KeyA)
+    ///         .timeout(Duration::from_secs(3))
+    ///     ).await;
+    ///     if result.is_err(){
+    ///         println!("Time is up!");
+    ///     }
+    /// });
+    /// ```
+    fn timeout(self, duration: Duration) -> ActionOrSeed;
+}
+
+impl<I1, O1, A, Re> Timeout<I1, O1, A> for Re
+where
+    I1: 'static,
+    O1: 'static,
+    Re: Remake<I1, O1, Result<O1, Elapsed>, A> + 'static,
+{
+    #[inline]
+    fn timeout(self, duration: Duration) -> A {
+        self.remake(move |r1, o1, output| TimeoutRunner {
+            r1,
+            o1,
+            output,
+            timer: Timer::new(duration, TimerMode::Once),
+        })
+    }
+}
+
+struct TimeoutRunner<O> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    output: Output<Result<O, Elapsed>>,
+    timer: Timer,
+}
+
+impl<O> Runner for TimeoutRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.r1.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Running => {}
+            RunnerIs::Completed => {
+                let o = self.o1.take().expect("An output value hasn't been set!!!");
+                self.output.set(Ok(o));
+                return RunnerIs::Completed;
+            }
+        }
+
+        let delta = world.resource::<Time>().delta();
+        if self.timer.tick(delta).finished() {
+            self.output.set(Err(Elapsed));
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::timeout::{Elapsed, Timeout};
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn ok_if_completed_in_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| 3)
+                    .timeout(Duration::from_secs(1))
+                    .pipe(once::run(|In(result): In<Result<usize, Elapsed>>, mut count: ResMut<Count>| {
+                        count.0 = result.unwrap();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn elapsed_if_not_completed_in_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, wait::until(|| false)
+                    .timeout(Duration::from_millis(350)),
+                ).await;
+                if result == Err(Elapsed) {
+                    task.will(Update, once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })).await;
+                }
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -1,30 +1,84 @@
 //!  The [wait] module defines actions that continue to execute every frame according to specified conditions.
 
 use crate::action::seed::ActionSeed;
+use crate::action::timeout::{Elapsed, Timeout};
 use crate::prelude::{wait, RunnerIs};
 use crate::runner::{CancellationHandlers, Output, Runner};
 pub use _any::any;
+pub use _any_of::any_of;
 pub use _both::both;
+pub use _debounce::debounce;
 pub use _either::*;
-pub use all::{all, private};
+pub use _future::future;
+pub use _optional::optional;
+pub use _progress::{progress, Progress};
+pub use _select::*;
+pub use _trigger::{trigger, trigger_on};
+pub(crate) use _trigger::despawn_dropped_observers;
+pub use all::{all, join_all, private};
 use bevy::prelude::{In, IntoSystem, System, SystemIn, SystemInput, World};
+use std::time::Duration;
 
 #[path = "wait/any.rs"]
 mod _any;
+#[path = "wait/any_of.rs"]
+mod _any_of;
 #[path = "wait/both.rs"]
 mod _both;
+#[path = "wait/debounce.rs"]
+mod _debounce;
 #[path = "wait/either.rs"]
 mod _either;
+#[path = "wait/future.rs"]
+mod _future;
+#[path = "wait/optional.rs"]
+mod _optional;
+#[path = "wait/progress.rs"]
+mod _progress;
+#[path = "wait/select.rs"]
+mod _select;
+#[path = "wait/trigger.rs"]
+mod _trigger;
 mod all;
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+pub mod animation;
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod asset;
 #[cfg(feature = "audio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
 pub mod audio;
+pub mod channel;
+#[cfg(any(feature = "avian2d", feature = "avian3d", feature = "rapier2d", feature = "rapier3d"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "avian2d", feature = "avian3d", feature = "rapier2d", feature = "rapier3d"))))]
+pub mod collision;
+pub mod component;
+pub mod counter;
+pub mod entity;
 pub mod event;
+#[cfg(feature = "gamepad")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gamepad")))]
+pub mod gamepad;
 pub mod input;
+pub mod message;
+pub mod query;
+pub mod res;
+#[cfg(feature = "scene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scene")))]
+pub mod scene;
 #[cfg(feature = "state")]
 #[cfg_attr(docsrs, doc(cfg(feature = "state")))]
 pub mod state;
+pub mod selector;
 pub mod switch;
+pub mod timer;
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+pub mod ui;
+#[cfg(feature = "window")]
+#[cfg_attr(docsrs, doc(cfg(feature = "window")))]
+pub mod window;
 
 /// Run until it returns [`Option::Some`].
 /// The contents of Some will be return value of the task.
@@ -84,6 +138,110 @@ where
     wait::output(system.pipe(|In(finish): In<bool>| if finish { Some(()) } else { None }))
 }
 
+/// Run until `predicate` returns true.
+///
+/// Unlike [`wait::until`](until), the predicate receives the [`World`] directly,
+/// so it can express conditions that are only known at runtime, such as component lookups by [`ComponentId`](bevy::ecs::component::ComponentId).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::any::TypeId;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// Reactor::schedule(|task| async move{
+///     let type_id = TypeId::of::<Enemy>();
+///     task.will(Update, wait::until_world(move |world: &World|{
+///         world.components().get_id(type_id).is_some_and(|id| {
+///             world.archetypes().iter().any(|archetype| archetype.contains(id) && !archetype.is_empty())
+///         })
+///     })).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn until_world<F>(mut predicate: F) -> ActionSeed
+where
+    F: FnMut(&World) -> bool + Send + Sync + 'static,
+{
+    wait::until(move |world: &World| predicate(world))
+}
+
+/// Run until it returns [`Option::Some`] or `duration` elapses.
+///
+/// The output is `Ok` with the contents of Some if the system returned it in time,
+/// otherwise `Err(`[`Elapsed`]`)`.
+///
+/// This is the same as `wait::output(system).timeout(duration)`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, wait::output_or_timeout(Duration::from_secs(3), |keys: Res<ButtonInput<KeyCode>>|{
+///         keys.get_just_pressed().next().copied()
+///     })).await;
+///     if let Ok(key) = result {
+///         println!("{key:?} pressed");
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn output_or_timeout<Sys, I, O, Marker>(
+    duration: Duration,
+    system: Sys,
+) -> ActionSeed<I::Inner<'static>, Result<O, Elapsed>>
+where
+    Sys: IntoSystem<I, Option<O>, Marker> + Send + Sync + 'static,
+    I: SystemInput + 'static,
+    I::Inner<'static>: Clone,
+    O: 'static,
+{
+    wait::output(system).timeout(duration)
+}
+
+/// Run until it returns true or `duration` elapses.
+///
+/// The output is `Ok` if the system returned true in time, otherwise `Err(`[`Elapsed`]`)`.
+///
+/// This is the same as `wait::until(system).timeout(duration)`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, wait::until_or_timeout(Duration::from_secs(3), |keys: Res<ButtonInput<KeyCode>>|{
+///         keys.just_pressed(KeyCode::Enter)
+///     })).await;
+///     if result.is_err() {
+///         println!("Time is up!");
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn until_or_timeout<I, Sys, M>(
+    duration: Duration,
+    system: Sys,
+) -> ActionSeed<I::Inner<'static>, Result<(), Elapsed>>
+where
+    Sys: IntoSystem<I, bool, M> + Send + Sync + 'static,
+    I: SystemInput + 'static,
+    I::Inner<'static>: Clone,
+{
+    wait::until(system).timeout(duration)
+}
+
 struct WaitRunner<Sys, O>
 where
     Sys: System,
@@ -120,14 +278,20 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::action::timeout::Elapsed;
     use crate::action::wait::until;
     use crate::action::{once, wait};
-    use crate::prelude::Reactor;
+    use crate::prelude::{Pipe, Reactor};
     use crate::tests::test_app;
     use bevy::app::{AppExit, PreUpdate, Startup};
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{Commands, EventWriter, In, Local, Update};
+    use bevy::prelude::{Commands, EventWriter, In, Local, Res, ResMut, Update, World};
+    use bevy::time::TimeUpdateStrategy;
     use bevy_test_helper::event::{TestEvent1, TestEvent2};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
 
     #[test]
     fn count_up() {
@@ -244,4 +408,71 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
+
+    #[test]
+    fn ok_if_until_returns_true_in_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until_or_timeout(Duration::from_millis(350), |b: Res<Bool>| **b)
+                    .pipe(once::run(|In(result): In<Result<(), Elapsed>>, mut count: ResMut<Count>| {
+                        if result.is_ok() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.set_bool(true);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn err_if_until_timed_out() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until_or_timeout(Duration::from_millis(350), |b: Res<Bool>| **b)
+                    .pipe(once::run(|In(result): In<Result<(), Elapsed>>, mut count: ResMut<Count>| {
+                        if result.is_err() {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn until_world_reads_world() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until_world(|world: &World| {
+                    **world.resource::<Bool>()
+                })).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.set_bool(true);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
 }
@@ -5,7 +5,8 @@ use bevy::prelude::World;
 /// Wait until all the actions are completed.
 ///
 /// The output value of this function is `()`.
-/// If you need the outputs, consider using [`wait_all!`](crate::wait_all) instead.
+/// If you need the outputs, consider using [`wait::join_all`](crate::prelude::wait::join_all)
+/// or [`wait_all!`](crate::wait_all) instead.
 ///
 /// # Examples
 ///
@@ -62,9 +63,85 @@ impl Runner for AllRunner {
     }
 }
 
+/// Wait until all the actions are completed and collect their outputs.
+///
+/// The outputs are returned in the same order as the passed actions,
+/// regardless of the order in which they completed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let outputs: Vec<usize> = task.will(Update, wait::join_all().with([
+///         once::run(|| 1),
+///         delay::frames().with(3).map(|_| 2).omit_input(),
+///     ])).await;
+///     assert_eq!(outputs, vec![1, 2]);
+/// });
+/// ```
+pub fn join_all<Actions, O>() -> ActionSeed<Actions, Vec<O>>
+where
+    Actions: IntoIterator<Item=ActionSeed<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(|actions: Actions, output| {
+        let (runners, outputs): (Vec<_>, Vec<_>) = actions
+            .into_iter()
+            .map(|seed| {
+                let o = Output::default();
+                (Some(seed.with(()).create_runner(o.clone())), o)
+            })
+            .unzip();
+        JoinAllRunner {
+            runners,
+            outputs,
+            output,
+        }
+    })
+}
+
+struct JoinAllRunner<O> {
+    runners: Vec<Option<BoxedRunner>>,
+    outputs: Vec<Output<O>>,
+    output: Output<Vec<O>>,
+}
+
+impl<O> Runner for JoinAllRunner<O> {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        for slot in self.runners.iter_mut() {
+            let Some(runner) = slot else {
+                continue;
+            };
+            match runner.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Completed => {
+                    slot.take();
+                }
+                RunnerIs::Running => {}
+            }
+        }
+        if self.runners.iter().all(Option::is_none) {
+            let outputs = self
+                .outputs
+                .iter()
+                .map(|o| o.take().expect("An output value hasn't been set!!!"))
+                .collect();
+            self.output.set(outputs);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
 /// Wait until all tasks done.
 ///
 /// The return value type is tuple, its length is equal to the number of as passed tasks.
+/// Up to 13 actions can be passed, and unlike nesting [`wait::both`](crate::prelude::wait::both),
+/// the outputs are not nested.
 ///
 /// If you don't need the outputs of the actions or want to pass a collection of actions,
 /// consider using [`wait::all`](crate::prelude::wait::all()) instead.
@@ -212,16 +289,58 @@ pub mod private {
 mod tests {
     use crate::action::delay;
     use crate::actions;
-    use crate::prelude::{once, wait, Pipe, Then};
+    use crate::prelude::{once, wait, Map, OmitInput, Pipe, Then};
     use crate::reactor::Reactor;
     use crate::tests::{decrement_count, exit_reader, increment_count, test_app};
     use bevy::app::{AppExit, Startup, Update};
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{Commands, EventWriter, Local};
+    use bevy::prelude::{Commands, EventWriter, In, Local, ResMut};
     use bevy_test_helper::event::{DirectEvents, TestEvent1, TestEvent2};
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
+    #[test]
+    fn join_all_outputs_in_passed_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::join_all()
+                        .with([
+                            delay::frames().with(2).map(|_| 1).omit_input(),
+                            once::run(|| 2),
+                            delay::frames().with(1).map(|_| 3).omit_input(),
+                        ])
+                        .pipe(once::run(|In(outputs): In<Vec<usize>>, mut count: ResMut<Count>| {
+                            count.0 = outputs.into_iter().fold(0, |acc, o| acc * 10 + o);
+                        }))
+                })
+                    .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(123));
+    }
+
+    #[test]
+    fn join_all_empty_actions() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let outputs: Vec<usize> = task.will(Update, wait::join_all().with([])).await;
+                assert!(outputs.is_empty());
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
     #[test]
     fn wai_all_actions() {
         let mut app = test_app();
@@ -334,4 +453,31 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
+
+    #[test]
+    fn wait_all_flattens_eight_outputs() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let outputs = task.will(Update, wait_all![
+                    once::run(|| 1),
+                    once::run(|| 2),
+                    delay::frames().with(1).map(|_| 3),
+                    once::run(|| 4),
+                    once::run(|| 5),
+                    delay::frames().with(2).map(|_| 6),
+                    once::run(|| 7),
+                    once::run(|| 8),
+                ]).await;
+                assert_eq!(outputs, (1, 2, 3, 4, 5, 6, 7, 8));
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
 }
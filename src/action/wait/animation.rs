@@ -0,0 +1,203 @@
+//! [`wait::animation`] creates a task related to waiting to [`AnimationPlayer`].
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::animation::{AnimationNodeIndex, AnimationPlayer};
+use bevy::prelude::{Entity, In, Local, Query};
+
+/// Waits until the animation of the passed node played by the [`AnimationPlayer`] of the entity has finished.
+///
+/// It also completes if the animation is not playing.
+/// Note that a repeating animation never finishes; use [`cycle_completed`] to wait for a loop boundary.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::animation::AnimationNodeIndex;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|players: Query<Entity, With<AnimationPlayer>>| {
+///         players.single()
+///     })).await;
+///     let node = AnimationNodeIndex::new(1);
+///     task.will(Update, wait::animation::finished().with((entity, node))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn finished() -> ActionSeed<(Entity, AnimationNodeIndex)> {
+    wait::until(|In((entity, node)): In<(Entity, AnimationNodeIndex)>, players: Query<&AnimationPlayer>| {
+        players
+            .get(entity)
+            .is_ok_and(|player| player.animation(node).is_none_or(|animation| animation.is_finished()))
+    })
+}
+
+/// Waits until all animations played by the [`AnimationPlayer`] of the passed entity have finished.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|players: Query<Entity, With<AnimationPlayer>>| {
+///         players.single()
+///     })).await;
+///     task.will(Update, wait::animation::all_finished().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn all_finished() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, players: Query<&AnimationPlayer>| {
+        players
+            .get(entity)
+            .is_ok_and(|player| player.all_finished())
+    })
+}
+
+/// Waits until the animation of the passed node completes its current cycle.
+///
+/// Unlike [`finished`], this also completes at each loop boundary of a repeating animation.
+/// It also completes if the animation is not playing.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::animation::AnimationNodeIndex;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|players: Query<Entity, With<AnimationPlayer>>| {
+///         players.single()
+///     })).await;
+///     let node = AnimationNodeIndex::new(1);
+///     task.will(Update, wait::animation::cycle_completed().with((entity, node))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn cycle_completed() -> ActionSeed<(Entity, AnimationNodeIndex)> {
+    wait::until(|In((entity, node)): In<(Entity, AnimationNodeIndex)>,
+                 mut started: Local<Option<u32>>,
+                 players: Query<&AnimationPlayer>| {
+        let Ok(player) = players.get(entity) else {
+            return false;
+        };
+        let Some(animation) = player.animation(node) else {
+            return true;
+        };
+        let completions = animation.completions();
+        match *started {
+            Some(started) => started < completions || animation.is_finished(),
+            None => {
+                started.replace(completions);
+                animation.is_finished()
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::animation::graph::{AnimationGraph, AnimationGraphHandle};
+    use bevy::animation::{AnimationClip, AnimationNodeIndex, AnimationPlayer, AnimationPlugin};
+    use bevy::app::{Startup, Update};
+    use bevy::asset::{AssetPlugin, Assets};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::{Commands, Entity};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    fn node() -> AnimationNodeIndex {
+        AnimationNodeIndex::new(1)
+    }
+
+    fn spawn_playing(app: &mut bevy::app::App) -> Entity {
+        let mut player = AnimationPlayer::default();
+        player.play(node());
+        app.world_mut().spawn(player).id()
+    }
+
+    #[test]
+    fn wait_until_finished() {
+        let mut app = test_app();
+        let entity = spawn_playing(&mut app);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::animation::finished()
+                    .with((entity, node()))
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().get_mut::<AnimationPlayer>(entity).unwrap().stop(node());
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_all_finished() {
+        let mut app = test_app();
+        let entity = spawn_playing(&mut app);
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::animation::all_finished()
+                    .with(entity)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().get_mut::<AnimationPlayer>(entity).unwrap().stop_all();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_cycle_completed() {
+        let mut app = test_app();
+        app
+            .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default(), AnimationPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let mut clip = AnimationClip::default();
+        clip.set_duration(1.);
+        let clip = app.world_mut().resource_mut::<Assets<AnimationClip>>().add(clip);
+        let (graph, node) = AnimationGraph::from_clip(clip);
+        let graph = app.world_mut().resource_mut::<Assets<AnimationGraph>>().add(graph);
+        let mut player = AnimationPlayer::default();
+        player.play(node).repeat();
+        let entity = app.world_mut().spawn((player, AnimationGraphHandle(graph))).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::animation::cycle_completed()
+                    .with((entity, node))
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+
+        for _ in 0..10 {
+            app.update();
+        }
+        let player = app.world().get::<AnimationPlayer>(entity).unwrap();
+        assert!(0 < player.animation(node).unwrap().completions());
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::prelude::World;
+
+use crate::prelude::ActionSeed;
+use crate::runner::{BoxedRunner, CancellationHandlers, Output, Runner, RunnerIs};
+
+/// Wait until the execution of one of the actions is completed.
+///
+/// Unlike [`wait::any`](crate::prelude::wait::any), the output value is a tuple of
+/// the index of the completed action and its output.
+///
+/// The remaining actions are dropped as soon as one of them completes.
+///
+/// # Panics
+///
+/// Panicked if actions is empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let (index, key): (usize, KeyCode) = task.will(Update, wait::any_of().with([
+///         wait::input::just_pressed().with(KeyCode::KeyA).map(|_| KeyCode::KeyA).omit_input(),
+///         wait::input::just_pressed().with(KeyCode::KeyB).map(|_| KeyCode::KeyB).omit_input(),
+///     ])).await;
+/// });
+/// ```
+pub fn any_of<Actions, O>() -> ActionSeed<Actions, (usize, O)>
+where
+    Actions: IntoIterator<Item=ActionSeed<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |actions: Actions, output| {
+        let runners = actions
+            .into_iter()
+            .map(|action| {
+                let o = Output::default();
+                (action.with(()).create_runner(o.clone()), o)
+            })
+            .collect::<Vec<_>>();
+        if runners.is_empty() {
+            panic!("The length of actions passed to `wait::any_of` must be greater than 0.")
+        }
+
+        AnyOfRunner { output, runners }
+    })
+}
+
+struct AnyOfRunner<O> {
+    output: Output<(usize, O)>,
+    runners: Vec<(BoxedRunner, Output<O>)>,
+}
+
+impl<O> Runner for AnyOfRunner<O> {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let mut finished = None;
+        for (i, (runner, o)) in self.runners.iter_mut().enumerate() {
+            match runner.run(world, token) {
+                RunnerIs::Completed => {
+                    let out = o.take().expect("An output value hasn't been set!!!");
+                    finished.replace((i, out));
+                    break;
+                }
+                RunnerIs::Canceled => {
+                    return RunnerIs::Canceled;
+                }
+                RunnerIs::Running => continue
+            }
+        }
+        if let Some(finished) = finished {
+            self.runners.clear();
+            self.output.set(finished);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{Map, OmitInput, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::Startup;
+    use bevy::prelude::{Commands, ResMut, Update};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn output_index_and_value_of_winner() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let (index, value) = task
+                    .will(
+                        Update,
+                        wait::any_of().with([
+                            delay::frames().with(3).map(|_| 3).omit_input(),
+                            delay::frames().with(1).map(|_| 1).omit_input(),
+                            wait::until(|| false).map(|_| 0),
+                        ]),
+                    )
+                    .await;
+                task.will(Update, once::run(move |mut count: ResMut<Count>| {
+                    count.0 = index * 10 + value;
+                })).await;
+            }));
+        });
+
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(11));
+    }
+
+    #[test]
+    fn losers_are_not_run_after_winner_completed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::any_of().with([
+                        once::run(|| 1),
+                        wait::until(|mut count: ResMut<Count>| {
+                            count.increment();
+                            false
+                        })
+                            .map(|_| 0),
+                    ]),
+                )
+                    .await;
+            }));
+        });
+
+        app.update();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+    }
+}
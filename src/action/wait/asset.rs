@@ -0,0 +1,254 @@
+//! [`wait::asset`] creates a task related to waiting to load assets.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::asset::{Asset, AssetLoadError, AssetServer, Assets, Handle, LoadState, RecursiveDependencyLoadState, UntypedAssetId, UntypedHandle};
+use bevy::prelude::{Commands, In, Res, Resource};
+use std::sync::Arc;
+
+/// The progress of [`wait::asset::all_loaded`].
+///
+/// This resource is updated every frame while waiting, so it can be used to display a loading bar.
+#[derive(Resource, Default, Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub struct AssetLoadProgress {
+    /// The number of loaded assets.
+    pub loaded: usize,
+    /// The number of all assets being waited for.
+    pub total: usize,
+}
+
+impl AssetLoadProgress {
+    /// Returns the ratio of loaded assets in the range `[0, 1]`.
+    ///
+    /// Returns `1` if there are no assets.
+    #[inline]
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
+
+/// Waits until the asset and all of its dependencies have been loaded.
+///
+/// The output is `Ok` with the handle if loaded,
+/// or `Err` with the reason if the load of the asset or any of its dependencies failed.
+///
+/// Assets added directly to [`Assets`] rather than loaded by [`AssetServer`]
+/// are treated as loaded once they exist in [`Assets`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let handle: Handle<Image> = task.will(Update, once::run(|asset_server: Res<AssetServer>| {
+///         asset_server.load("player.png")
+///     })).await;
+///     match task.will(Update, wait::asset::loaded().with(handle)).await {
+///         Ok(handle) => println!("loaded: {handle:?}"),
+///         Err(e) => println!("failed: {e}"),
+///     }
+/// });
+/// ```
+pub fn loaded<A>() -> ActionSeed<Handle<A>, Result<Handle<A>, Arc<AssetLoadError>>>
+where
+    A: Asset,
+{
+    wait::output(|In(handle): In<Handle<A>>, asset_server: Res<AssetServer>, assets: Res<Assets<A>>| {
+        match asset_server.get_load_state(&handle) {
+            Some(LoadState::NotLoaded) | None if assets.contains(&handle) => Some(Ok(handle)),
+            _ => load_result(&asset_server, &handle).map(|result| result.map(|_| handle)),
+        }
+    })
+}
+
+/// Returns `Some(Ok(()))` if the asset and all of its dependencies have been loaded,
+/// or `Some(Err(_))` if the asset or any of its dependencies failed to load.
+fn load_result(asset_server: &AssetServer, id: impl Into<UntypedAssetId>) -> Option<Result<(), Arc<AssetLoadError>>> {
+    let id = id.into();
+    if let Some(LoadState::Failed(e)) = asset_server.get_load_state(id) {
+        return Some(Err(e));
+    }
+    if let Some(RecursiveDependencyLoadState::Failed(e)) = asset_server.get_recursive_dependency_load_state(id) {
+        return Some(Err(e));
+    }
+    asset_server.is_loaded_with_dependencies(id).then_some(Ok(()))
+}
+
+/// Waits until all the assets and their dependencies have been loaded.
+///
+/// While waiting, the progress is written into [`AssetLoadProgress`].
+/// The output is `Ok` with the handles if all of them are loaded,
+/// or `Err` with the reason as soon as the load of any asset or its dependencies fails.
+///
+/// As with [`wait::asset::loaded`], assets added directly to [`Assets`] rather than loaded by [`AssetServer`]
+/// are treated as loaded.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let handles: Vec<UntypedHandle> = task.will(Update, once::run(|asset_server: Res<AssetServer>| {
+///         vec![
+///             asset_server.load::<Image>("player.png").untyped(),
+///             asset_server.load::<Image>("enemy.png").untyped(),
+///         ]
+///     })).await;
+///     task.will(Update, wait::asset::all_loaded().with(handles)).await.unwrap();
+/// });
+///
+/// fn loading_bar(progress: Res<wait::asset::AssetLoadProgress>) {
+///     println!("{}%", progress.fraction() * 100.);
+/// }
+/// ```
+pub fn all_loaded() -> ActionSeed<Vec<UntypedHandle>, Result<Vec<UntypedHandle>, Arc<AssetLoadError>>> {
+    wait::output(|In(handles): In<Vec<UntypedHandle>>, mut commands: Commands, asset_server: Res<AssetServer>| {
+        let mut loaded = 0;
+        for handle in handles.iter() {
+            if asset_server.get_load_state(handle).is_none() {
+                loaded += 1;
+                continue;
+            }
+            match load_result(&asset_server, handle) {
+                Some(Ok(())) => loaded += 1,
+                Some(Err(e)) => return Some(Err(e)),
+                None => {}
+            }
+        }
+        commands.insert_resource(AssetLoadProgress {
+            loaded,
+            total: handles.len(),
+        });
+        (loaded == handles.len()).then_some(Ok(handles))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait::asset::AssetLoadProgress;
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::asset::{Asset, AssetApp, AssetLoadError, AssetPlugin, AssetServer, Assets, Handle, UntypedHandle};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::{Commands, In, Res, ResMut, Resource};
+    use bevy::reflect::TypePath;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset;
+
+    #[derive(Resource, Default)]
+    struct Loaded(Option<bool>);
+
+    fn asset_test_app() -> App {
+        let mut app = test_app();
+        app
+            .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<TestAsset>()
+            .init_resource::<Loaded>();
+        app
+    }
+
+    fn update_until_output(app: &mut App) -> Option<bool> {
+        for _ in 0..100 {
+            app.update();
+            if let Some(loaded) = app.world().resource::<Loaded>().0 {
+                return Some(loaded);
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        None
+    }
+
+    #[test]
+    fn loaded_if_added_to_assets() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|mut assets: ResMut<Assets<TestAsset>>| {
+                    assets.add(TestAsset)
+                })
+                    .pipe(wait::asset::loaded())
+                    .pipe(once::run(|In(result): In<Result<Handle<TestAsset>, Arc<AssetLoadError>>>, mut loaded: ResMut<Loaded>| {
+                        loaded.0 = Some(result.is_ok());
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert_eq!(app.world().resource::<Loaded>().0, Some(true));
+    }
+
+    #[test]
+    fn err_if_failed_to_load() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|asset_server: Res<AssetServer>| {
+                    asset_server.load::<TestAsset>("not_exists.asset")
+                })
+                    .pipe(wait::asset::loaded())
+                    .pipe(once::run(|In(result): In<Result<Handle<TestAsset>, Arc<AssetLoadError>>>, mut loaded: ResMut<Loaded>| {
+                        loaded.0 = Some(result.is_ok());
+                    })),
+                ).await;
+            }));
+        });
+        assert_eq!(update_until_output(&mut app), Some(false));
+    }
+
+    #[test]
+    fn all_loaded_if_added_to_assets() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|mut assets: ResMut<Assets<TestAsset>>| {
+                    vec![assets.add(TestAsset).untyped(), assets.add(TestAsset).untyped()]
+                })
+                    .pipe(wait::asset::all_loaded())
+                    .pipe(once::run(|In(result): In<Result<Vec<UntypedHandle>, Arc<AssetLoadError>>>, mut loaded: ResMut<Loaded>| {
+                        loaded.0 = Some(result.is_ok());
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        assert_eq!(app.world().resource::<Loaded>().0, Some(true));
+        app.assert_resource_eq(AssetLoadProgress { loaded: 2, total: 2 });
+    }
+
+    #[test]
+    fn all_loaded_err_if_any_failed_to_load() {
+        let mut app = asset_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|mut assets: ResMut<Assets<TestAsset>>, asset_server: Res<AssetServer>| {
+                    vec![
+                        assets.add(TestAsset).untyped(),
+                        asset_server.load::<TestAsset>("not_exists.asset").untyped(),
+                    ]
+                })
+                    .pipe(wait::asset::all_loaded())
+                    .pipe(once::run(|In(result): In<Result<Vec<UntypedHandle>, Arc<AssetLoadError>>>, mut loaded: ResMut<Loaded>| {
+                        loaded.0 = Some(result.is_ok());
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(AssetLoadProgress { loaded: 1, total: 2 });
+        assert_eq!(update_until_output(&mut app), Some(false));
+    }
+}
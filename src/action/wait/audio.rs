@@ -1,12 +1,15 @@
 //! [`wait::audio`] creates a task related to waiting to audio.
 
-use bevy::audio::{AudioSink, AudioSinkPlayback};
-use bevy::prelude::{Commands, Entity, In, Query};
+use bevy::audio::{AudioSink, AudioSinkPlayback, SpatialAudioSink};
+use bevy::prelude::{AnyOf, Commands, Entity, In, Query};
 use crate::action::wait;
 use crate::prelude::seed::ActionSeed;
 
 /// Waits until the audio associated with the passed [`Entity`] has finished playing.
 ///
+/// Both [`AudioSink`] and [`SpatialAudioSink`] are supported.
+/// The entity is despawned after the audio has finished.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -23,11 +26,11 @@ use crate::prelude::seed::ActionSeed;
 /// ```
 pub fn finished() -> ActionSeed<Entity, ()> {
     wait::until(
-        |In(entity): In<Entity>, mut commands: Commands, audio: Query<(Entity, &AudioSink)>| {
-            let Ok((entity, audio)) = audio.get(entity) else {
+        |In(entity): In<Entity>, mut commands: Commands, audio: Query<(Entity, AnyOf<(&AudioSink, &SpatialAudioSink)>)>| {
+            let Ok((entity, sink)) = audio.get(entity) else {
                 return false;
             };
-            if audio.empty() {
+            if sink_empty(sink) {
                 commands.entity(entity).despawn();
                 true
             } else {
@@ -36,3 +39,64 @@ pub fn finished() -> ActionSeed<Entity, ()> {
         },
     )
 }
+
+fn sink_empty(sink: (Option<&impl AudioSinkPlayback>, Option<&impl AudioSinkPlayback>)) -> bool {
+    match sink {
+        (Some(sink), _) => sink.empty(),
+        (_, Some(sink)) => sink.empty(),
+        (None, None) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait::audio::sink_empty;
+    use bevy::audio::AudioSinkPlayback;
+
+    struct FakeSink(bool);
+
+    impl AudioSinkPlayback for FakeSink {
+        fn volume(&self) -> f32 {
+            1.
+        }
+
+        fn set_volume(&self, _: f32) {}
+
+        fn speed(&self) -> f32 {
+            1.
+        }
+
+        fn set_speed(&self, _: f32) {}
+
+        fn play(&self) {}
+
+        fn pause(&self) {}
+
+        fn is_paused(&self) -> bool {
+            false
+        }
+
+        fn stop(&self) {}
+
+        fn empty(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[test]
+    fn spatial_sink_empty() {
+        assert!(sink_empty((None::<&FakeSink>, Some(&FakeSink(true)))));
+        assert!(!sink_empty((None::<&FakeSink>, Some(&FakeSink(false)))));
+    }
+
+    #[test]
+    fn sink_empty_if_audio_sink_empty() {
+        assert!(sink_empty((Some(&FakeSink(true)), None::<&FakeSink>)));
+        assert!(!sink_empty((Some(&FakeSink(false)), None::<&FakeSink>)));
+    }
+
+    #[test]
+    fn not_empty_without_sink() {
+        assert!(!sink_empty((None::<&FakeSink>, None::<&FakeSink>)));
+    }
+}
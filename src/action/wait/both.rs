@@ -6,6 +6,13 @@ use bevy::prelude::World;
 
 /// Run until both tasks done.
 ///
+/// Both actions are run in every frame until each is completed,
+/// so they make progress in the same ticks rather than one after the other.
+/// The output is a tuple of both outputs, like zipping them.
+///
+/// Nesting `both` to wait for more actions also nests the output tuples;
+/// use [`wait_all!`](crate::wait_all) instead to get a flat tuple of up to 13 outputs.
+///
 /// ## Examples
 ///
 /// ```
@@ -25,10 +32,10 @@ pub fn both<LI, LO, RI, RO>(
     rhs: impl Into<Action<RI, RO>> + 'static,
 ) -> Action<(LI, RI), (LO, RO)>
     where
-        RI: Clone + 'static,
-        LI: Clone + 'static,
-        LO: Send + 'static,
-        RO: Send + 'static,
+        RI: 'static,
+        LI: 'static,
+        LO: 'static,
+        RO: 'static,
 {
     let Action(i1, s1) = lhs.into();
     let Action(i2, s2) = rhs.into();
@@ -71,4 +78,46 @@ impl<O1, O2> Runner for BothRunner<O1, O2>
         }
         output_combine!(&self.o1, &self.o2, self.output)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Local, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn both_run_in_same_ticks() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::both(
+                    wait::output(|mut ticks: Local<usize>, mut count: ResMut<Count>| {
+                        *ticks += 1;
+                        count.increment();
+                        (*ticks == 2).then_some(*ticks)
+                    }),
+                    wait::output(|mut ticks: Local<usize>, mut count: ResMut<Count>| {
+                        *ticks += 1;
+                        count.increment();
+                        (*ticks == 2).then_some("done")
+                    }),
+                )
+                    .pipe(once::run(|In((ticks, word)): In<(usize, &'static str)>, mut count: ResMut<Count>| {
+                        assert_eq!(ticks, 2);
+                        assert_eq!(word, "done");
+                        count.0 *= 10;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(40));
+    }
+}
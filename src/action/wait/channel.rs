@@ -0,0 +1,99 @@
+//! [`wait::channel`] creates a task related to waiting for messages from channels.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use std::sync::mpsc::{Receiver, RecvError, TryRecvError};
+use std::sync::Mutex;
+
+/// Waits until a message is received from `receiver`, and returns it.
+///
+/// The receiver is polled with [`Receiver::try_recv`] every tick, so this never blocks the main thread.
+/// If all senders are dropped before a message arrives, it returns `Err(`[`RecvError`]`)`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let (tx, rx) = std::sync::mpsc::channel();
+/// std::thread::spawn(move || {
+///     tx.send("loaded").unwrap();
+/// });
+/// let recv = wait::channel::recv(rx);
+/// Reactor::schedule(|task| async move{
+///     let message = task.will(Update, recv).await;
+///     assert_eq!(message, Ok("loaded"));
+/// });
+/// ```
+#[inline(always)]
+pub fn recv<T>(receiver: Receiver<T>) -> ActionSeed<(), Result<T, RecvError>>
+where
+    T: Send + 'static,
+{
+    let receiver = Mutex::new(receiver);
+    wait::output(move || {
+        match receiver.lock().ok()?.try_recv() {
+            Ok(message) => Some(Ok(message)),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err(RecvError)),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::sync::mpsc::{channel, RecvError};
+    use std::sync::Mutex;
+
+    #[test]
+    fn recv_message() {
+        let mut app = test_app();
+        let (tx, rx) = channel();
+        let rx = Mutex::new(Some(rx));
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let recv = wait::channel::recv(rx.lock().unwrap().take().unwrap());
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, recv.pipe(once::run(|In(message): In<Result<usize, RecvError>>, mut count: ResMut<Count>| {
+                    if let Ok(num) = message {
+                        count.0 = num;
+                    }
+                }))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        tx.send(3).unwrap();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn err_if_disconnected() {
+        let mut app = test_app();
+        let (tx, rx) = channel();
+        let rx = Mutex::new(Some(rx));
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let recv = wait::channel::recv(rx.lock().unwrap().take().unwrap());
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, recv.pipe(once::run(|In(message): In<Result<usize, RecvError>>, mut count: ResMut<Count>| {
+                    if message.is_err() {
+                        count.increment();
+                    }
+                }))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        drop(tx);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,330 @@
+//! [`wait::collision`] creates a task related to waiting for collisions of physics engines.
+//!
+//! Each physics backend is provided as a submodule enabled by the feature flag of the same name.
+//! Collisions that happened before the action starts are not received.
+//!
+//! - `avian2d`: [`wait::collision::avian2d`](crate::prelude::wait::collision::avian2d)
+//! - `avian3d`: [`wait::collision::avian3d`](crate::prelude::wait::collision::avian3d)
+//! - `rapier2d`: [`wait::collision::rapier2d`](crate::prelude::wait::collision::rapier2d)
+//! - `rapier3d`: [`wait::collision::rapier3d`](crate::prelude::wait::collision::rapier3d)
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::{Entity, Event, Events, In, Local, Res};
+
+fn wait_collision<E, F>(pair: F) -> ActionSeed<Entity, Entity>
+where
+    E: Event,
+    F: Fn(&E) -> Option<(Entity, Entity)> + Send + Sync + 'static,
+{
+    wait::output(move |In(entity): In<Entity>, mut cursor: Local<Option<EventCursor<E>>>, events: Res<Events<E>>| {
+        let cursor = cursor.get_or_insert_with(|| events.get_cursor_current());
+        cursor.read(&events).find_map(|event| {
+            let (e1, e2) = pair(event)?;
+            if e1 == entity {
+                Some(e2)
+            } else if e2 == entity {
+                Some(e1)
+            } else {
+                None
+            }
+        })
+    })
+}
+
+/// Waits for collisions detected by [`avian2d`](::avian2d).
+#[cfg(feature = "avian2d")]
+#[cfg_attr(docsrs, doc(cfg(feature = "avian2d")))]
+pub mod avian2d {
+    use crate::prelude::seed::ActionSeed;
+    use ::avian2d::prelude::{CollisionEnded, CollisionStarted};
+    use bevy::prelude::Entity;
+
+    /// Waits until the passed entity starts colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::avian2d::started().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn started() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionStarted| Some((event.0, event.1)))
+    }
+
+    /// Waits until the passed entity stops colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::avian2d::stopped().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn stopped() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionEnded| Some((event.0, event.1)))
+    }
+}
+
+/// Waits for collisions detected by [`avian3d`](::avian3d).
+#[cfg(feature = "avian3d")]
+#[cfg_attr(docsrs, doc(cfg(feature = "avian3d")))]
+pub mod avian3d {
+    use crate::prelude::seed::ActionSeed;
+    use ::avian3d::prelude::{CollisionEnded, CollisionStarted};
+    use bevy::prelude::Entity;
+
+    /// Waits until the passed entity starts colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::avian3d::started().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn started() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionStarted| Some((event.0, event.1)))
+    }
+
+    /// Waits until the passed entity stops colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::avian3d::stopped().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn stopped() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionEnded| Some((event.0, event.1)))
+    }
+}
+
+/// Waits for collisions detected by [`bevy_rapier2d`](::bevy_rapier2d).
+///
+/// Note that rapier sends collision events only for colliders with `ActiveEvents::COLLISION_EVENTS`.
+#[cfg(feature = "rapier2d")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rapier2d")))]
+pub mod rapier2d {
+    use crate::prelude::seed::ActionSeed;
+    use ::bevy_rapier2d::prelude::CollisionEvent;
+    use bevy::prelude::Entity;
+
+    /// Waits until the passed entity starts colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::rapier2d::started().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn started() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionEvent| match event {
+            CollisionEvent::Started(e1, e2, _) => Some((*e1, *e2)),
+            CollisionEvent::Stopped(..) => None,
+        })
+    }
+
+    /// Waits until the passed entity stops colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::rapier2d::stopped().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn stopped() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionEvent| match event {
+            CollisionEvent::Stopped(e1, e2, _) => Some((*e1, *e2)),
+            CollisionEvent::Started(..) => None,
+        })
+    }
+}
+
+/// Waits for collisions detected by [`bevy_rapier3d`](::bevy_rapier3d).
+///
+/// Note that rapier sends collision events only for colliders with `ActiveEvents::COLLISION_EVENTS`.
+#[cfg(feature = "rapier3d")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rapier3d")))]
+pub mod rapier3d {
+    use crate::prelude::seed::ActionSeed;
+    use ::bevy_rapier3d::prelude::CollisionEvent;
+    use bevy::prelude::Entity;
+
+    /// Waits until the passed entity starts colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::rapier3d::started().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn started() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionEvent| match event {
+            CollisionEvent::Started(e1, e2, _) => Some((*e1, *e2)),
+            CollisionEvent::Stopped(..) => None,
+        })
+    }
+
+    /// Waits until the passed entity stops colliding with another entity, and returns the other entity.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let trigger_volume = task.will(Update, once::run(|mut commands: Commands| {
+    ///         commands.spawn_empty().id()
+    ///     })).await;
+    ///     let other = task.will(Update, wait::collision::rapier3d::stopped().with(trigger_volume)).await;
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn stopped() -> ActionSeed<Entity, Entity> {
+        super::wait_collision(|event: &CollisionEvent| match event {
+            CollisionEvent::Stopped(e1, e2, _) => Some((*e1, *e2)),
+            CollisionEvent::Started(..) => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{ActionSeed, Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Commands, Entity, Event, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[allow(unused)]
+    fn assert_collision<E: Event>(
+        seed: fn() -> ActionSeed<Entity, Entity>,
+        event: impl Fn(Entity, Entity) -> E,
+    ) {
+        let mut app = test_app();
+        app.add_event::<E>();
+        let e1 = app.world_mut().spawn_empty().id();
+        let e2 = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, seed()
+                    .with(e1)
+                    .pipe(once::run(move |In(other): In<Entity>, mut count: ResMut<Count>| {
+                        if other == e2 {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        send(&mut app, event(e2, e1));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        send(&mut app, event(e2, e1));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[allow(unused)]
+    fn send<E: Event>(app: &mut App, event: E) {
+        app.world_mut().send_event(event);
+    }
+
+    #[cfg(feature = "avian2d")]
+    #[test]
+    fn avian2d_started_and_stopped() {
+        use crate::prelude::wait::collision::avian2d;
+        use ::avian2d::prelude::{CollisionEnded, CollisionStarted};
+        assert_collision(avian2d::started, CollisionStarted);
+        assert_collision(avian2d::stopped, CollisionEnded);
+    }
+
+    #[cfg(feature = "avian3d")]
+    #[test]
+    fn avian3d_started_and_stopped() {
+        use crate::prelude::wait::collision::avian3d;
+        use ::avian3d::prelude::{CollisionEnded, CollisionStarted};
+        assert_collision(avian3d::started, CollisionStarted);
+        assert_collision(avian3d::stopped, CollisionEnded);
+    }
+
+    #[cfg(feature = "rapier2d")]
+    #[test]
+    fn rapier2d_started_and_stopped() {
+        use crate::prelude::wait::collision::rapier2d;
+        use ::bevy_rapier2d::prelude::CollisionEvent;
+        use ::bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+        assert_collision(rapier2d::started, |e1, e2| CollisionEvent::Started(e1, e2, CollisionEventFlags::empty()));
+        assert_collision(rapier2d::stopped, |e1, e2| CollisionEvent::Stopped(e1, e2, CollisionEventFlags::empty()));
+    }
+
+    #[cfg(feature = "rapier3d")]
+    #[test]
+    fn rapier3d_started_and_stopped() {
+        use crate::prelude::wait::collision::rapier3d;
+        use ::bevy_rapier3d::prelude::CollisionEvent;
+        use ::bevy_rapier3d::rapier::geometry::CollisionEventFlags;
+        assert_collision(rapier3d::started, |e1, e2| CollisionEvent::Started(e1, e2, CollisionEventFlags::empty()));
+        assert_collision(rapier3d::stopped, |e1, e2| CollisionEvent::Stopped(e1, e2, CollisionEventFlags::empty()));
+    }
+}
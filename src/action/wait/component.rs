@@ -0,0 +1,208 @@
+//! [`wait::component`] creates a task related to waiting for component changes.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::prelude::{Added, Changed, Component, Entity, In, Local, Query, RemovedComponents};
+
+/// Waits until the component `C` is added to any entity, and returns the entity.
+///
+/// Only the components added after this action starts are detected.
+/// If the components are added to multiple entities in the same frame, one of them is returned.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// Reactor::schedule(|task| async move{
+///     let enemy: Entity = task.will(Update, wait::component::added::<Enemy>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn added<C>() -> ActionSeed<(), Entity>
+where
+    C: Component,
+{
+    wait::output(|mut initialized: Local<bool>, query: Query<Entity, Added<C>>| {
+        // On the first run, all existing components are regarded as added.
+        if !*initialized {
+            *initialized = true;
+            return None;
+        }
+        query.iter().next()
+    })
+}
+
+/// Waits until the component `C` is removed from any entity, and returns the entity.
+///
+/// This also detects the removal caused by despawning the entity.
+/// Only the components removed after this action starts are detected.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Shield;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity: Entity = task.will(Update, wait::component::removed::<Shield>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn removed<C>() -> ActionSeed<(), Entity>
+where
+    C: Component,
+{
+    wait::output(|mut initialized: Local<bool>, mut removed: RemovedComponents<C>| {
+        // Skip the removals that happened before this action started.
+        if !*initialized {
+            *initialized = true;
+            removed.clear();
+            return None;
+        }
+        removed.read().next()
+    })
+}
+
+/// Waits until the component `C` on the passed entity is changed, and returns a clone of the new value.
+///
+/// Changes are detected by change detection, so mutably dereferencing the component counts as a change
+/// even if the value stays the same, and inserting the component also counts as a change.
+/// Only the changes made after this action starts are detected.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component, Clone)]
+/// struct Hp(u8);
+///
+/// Reactor::schedule(|task| async move{
+///     let player = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn(Hp(100)).id()
+///     })).await;
+///     let Hp(hp) = task.will(Update, wait::component::changed_on::<Hp>().with(player)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn changed_on<C>() -> ActionSeed<Entity, C>
+where
+    C: Component + Clone,
+{
+    wait::output(|In(entity): In<Entity>, mut initialized: Local<bool>, query: Query<&C, Changed<C>>| {
+        // On the first run, all existing components are regarded as changed.
+        if !*initialized {
+            *initialized = true;
+            return None;
+        }
+        query.get(entity).ok().cloned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Entity, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component)]
+    struct Target;
+
+    #[derive(Component)]
+    struct Detected;
+
+    #[test]
+    fn ignore_components_added_before_started() {
+        let mut app = test_app();
+        app.world_mut().spawn(Target);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::component::added::<Target>().pipe(once::run(|In(entity): In<Entity>, mut commands: Commands| {
+                    commands.entity(entity).insert(Detected);
+                }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert_eq!(app.world_mut().query::<&Detected>().iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn wait_until_added() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::component::added::<Target>().pipe(once::run(|In(entity): In<Entity>, mut commands: Commands| {
+                    commands.entity(entity).insert(Detected);
+                }))).await;
+            }));
+        });
+        app.update();
+        let entity = app.world_mut().spawn(Target).id();
+        app.update();
+        assert!(app.world().entity(entity).contains::<Detected>());
+    }
+
+    #[test]
+    fn wait_until_removed() {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn(Target).id();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::component::removed::<Target>().pipe(once::run(|In(entity): In<Entity>, mut commands: Commands| {
+                    commands.entity(entity).insert(Detected);
+                }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert!(!app.world().entity(entity).contains::<Detected>());
+
+        app.world_mut().entity_mut(entity).remove::<Target>();
+        app.update();
+        assert!(app.world().entity(entity).contains::<Detected>());
+    }
+
+    #[test]
+    fn wait_until_changed_on_entity() {
+        #[derive(Component, Clone)]
+        struct Hp(usize);
+
+        let mut app = test_app();
+        let target = app.world_mut().spawn(Hp(10)).id();
+        let other = app.world_mut().spawn(Hp(10)).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::component::changed_on::<Hp>().with(target)
+                    .pipe(once::run(|In(Hp(hp)): In<Hp>, mut count: ResMut<Count>| {
+                        count.0 = hp;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().get_mut::<Hp>(other).unwrap().0 = 3;
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().get_mut::<Hp>(target).unwrap().0 = 5;
+        app.update();
+        app.assert_resource_eq(Count(5));
+    }
+}
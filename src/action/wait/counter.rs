@@ -0,0 +1,79 @@
+//! [`wait::counter`] creates a task related to waiting [`Counter`]
+
+use bevy::prelude::{In, Res};
+use crate::action::switch::Counter;
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+
+/// Waits until [`Counter`] reaches the count passed as the input, that is, it is at least the count.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Workers;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::counter::reaches::<Workers>().with(3)).await;
+/// });
+/// ```
+#[inline]
+pub fn reaches<M>() -> ActionSeed<usize>
+    where M: Send + Sync + 'static
+{
+    wait::until(|In(n): In<usize>, counter: Option<Res<Counter<M>>>| {
+        n <= counter.map(|counter| counter.get()).unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use crate::action::wait;
+    use crate::prelude::{Counter, Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+
+    struct T;
+
+    #[test]
+    fn wait_until_counter_reaches() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::counter::reaches::<T>().with(2)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Counter::<T>::new(1));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().resource_mut::<Counter<T>>().increment();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn already_reached() {
+        let mut app = test_app();
+        app.insert_resource(Counter::<T>::new(3));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::counter::reaches::<T>().with(2)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::action::Action;
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{TimerMode, World};
+use bevy::time::{Time, Timer};
+use std::time::Duration;
+
+/// Waits until the action created by `factory` has not completed for `duration`
+/// since its last completion.
+///
+/// The action is re-created and run again every time it completes,
+/// and each completion restarts the quiet period.
+/// The output is that of the last completed action.
+///
+/// Note that this does not complete until the action has completed at least once.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     // Wait until the player stops pressing keys for 0.5 seconds.
+///     task.will(Update, wait::debounce(Duration::from_millis(500), || {
+///         wait::input::any_pressed().with(vec![KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD])
+///     })).await;
+/// });
+/// ```
+pub fn debounce<F, A, I, O>(duration: Duration, factory: F) -> ActionSeed<(), O>
+where
+    F: FnMut() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>>,
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| {
+        let mut factory = factory;
+        DebounceRunner {
+            factory: move || {
+                let o = Output::default();
+                let action: Action<I, O> = factory().into();
+                (action.create_runner(o.clone()), o)
+            },
+            duration,
+            runner: None,
+            timer: None,
+            last: None,
+            output,
+        }
+    })
+}
+
+struct DebounceRunner<F, O> {
+    factory: F,
+    duration: Duration,
+    runner: Option<(BoxedRunner, Output<O>)>,
+    timer: Option<Timer>,
+    last: Option<O>,
+    output: Output<O>,
+}
+
+impl<F, O> Runner for DebounceRunner<F, O>
+where
+    F: FnMut() -> (BoxedRunner, Output<O>),
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let (runner, o) = self.runner.get_or_insert_with(&mut self.factory);
+        match runner.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Running => {}
+            RunnerIs::Completed => {
+                self.last = o.take();
+                self.runner = None;
+                self.timer.replace(Timer::new(self.duration, TimerMode::Once));
+                return RunnerIs::Running;
+            }
+        }
+
+        let Some(timer) = self.timer.as_mut() else {
+            return RunnerIs::Running;
+        };
+        if timer.tick(world.resource::<Time>().delta()).finished() {
+            let last = self.last.take().expect("An output value hasn't been set!!!");
+            self.output.set(last);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Res, ResMut};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn complete_after_quiet_period() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::debounce(Duration::from_millis(250), || {
+                    wait::until(|b: Res<Bool>| **b)
+                })
+                    .pipe(once::run(|In(_): In<()>, mut count: ResMut<Count>| {
+                        count.increment();
+                    })),
+                ).await;
+            }));
+        });
+
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.set_bool(true);
+        for _ in 0..5 {
+            app.update();
+        }
+        // Keeps triggering, so it never becomes quiet.
+        app.assert_resource_eq(Count(0));
+
+        app.set_bool(false);
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,57 @@
+//! [`wait::entity`] creates a task related to waiting for entities.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::{Entity, In};
+
+/// Waits until the passed entity no longer exists.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let enemy = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, wait::entity::despawned().with(enemy)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawned() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, entities: &Entities| {
+        !entities.contains(entity)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_until_despawned() {
+        let mut app = test_app();
+        let entity = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::entity::despawned().with(entity).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().despawn(entity);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
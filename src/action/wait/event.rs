@@ -1,9 +1,10 @@
 //! [`wait::event`] creates a task related to waiting to receive events.
 
 use crate::prelude::seed::ActionSeed;
-use crate::prelude::wait;
+use crate::prelude::{wait, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
 use bevy::ecs::event::EventCursor;
-use bevy::prelude::{Event, Events, Local, ResMut};
+use bevy::prelude::{Event, Events, IntoSystem, Local, Res, ResMut, System, World};
 
 /// Waits until the specified event is sent
 ///
@@ -84,14 +85,139 @@ where
     )
 }
 
+/// Waits until an event that satisfies `predicate` is sent, and returns the event.
+///
+/// This is similar to [`wait::event::read`], except that events that don't satisfy `predicate` are ignored.
+/// If multiple events satisfy it in the same frame, the last one is returned.
+/// The events are not consumed, so other readers still receive them.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Damage(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let Damage(damage) = task.will(Update, wait::event::read_with(|Damage(damage)| 10 <= *damage)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn read_with<E>(predicate: impl Fn(&E) -> bool + Send + Sync + 'static) -> ActionSeed<(), E>
+where
+    E: Event + Clone,
+{
+    wait::output(
+        move |mut er: Local<Option<EventCursor<E>>>, events: Res<Events<E>>| {
+            if er.is_none() {
+                er.replace(events.get_cursor_current());
+                return events.iter_current_update_events().filter(|e| predicate(*e)).last().cloned();
+            }
+            er.as_mut().unwrap().read(&events).filter(|e| predicate(*e)).last().cloned()
+        },
+    )
+}
+
+/// Collects the events sent until `condition` returns true, and returns them.
+///
+/// The events sent in the frame in which `condition` returns true are also collected.
+/// Unlike [`wait::event::read`], the events are not consumed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Hit;
+///
+/// Reactor::schedule(|task| async move{
+///     let hits: Vec<Hit> = task.will(Update, wait::event::collect_until::<Hit, _, _>(
+///         |mut timer: Local<Option<Timer>>, time: Res<Time>| {
+///             timer
+///                 .get_or_insert_with(|| Timer::new(Duration::from_secs(1), TimerMode::Once))
+///                 .tick(time.delta())
+///                 .finished()
+///         }
+///     )).await;
+///     println!("combo: {}", hits.len());
+/// });
+/// ```
+pub fn collect_until<E, Sys, M>(condition: Sys) -> ActionSeed<(), Vec<E>>
+where
+    E: Event + Clone,
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+{
+    ActionSeed::new(move |_, output| CollectUntilRunner {
+        collector: IntoSystem::into_system(collect_events::<E>),
+        condition: IntoSystem::into_system(condition),
+        init: false,
+        events: Vec::new(),
+        output,
+    })
+}
+
+fn collect_events<E>(mut er: Local<Option<EventCursor<E>>>, events: Res<Events<E>>) -> Vec<E>
+where
+    E: Event + Clone,
+{
+    if let Some(er) = er.as_mut() {
+        er.read(&events).cloned().collect()
+    } else {
+        let collected = events.iter_current_update_events().cloned().collect();
+        er.replace(events.get_cursor_current());
+        collected
+    }
+}
+
+struct CollectUntilRunner<E, Collector, Sys> {
+    collector: Collector,
+    condition: Sys,
+    init: bool,
+    events: Vec<E>,
+    output: Output<Vec<E>>,
+}
+
+impl<E, Collector, Sys> Runner for CollectUntilRunner<E, Collector, Sys>
+where
+    E: 'static,
+    Collector: System<In=(), Out=Vec<E>>,
+    Sys: System<In=(), Out=bool>,
+{
+    fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        if !self.init {
+            self.collector.initialize(world);
+            self.condition.initialize(world);
+            self.init = true;
+        }
+        let events = self.collector.run((), world);
+        self.events.extend(events);
+
+        let finished = self.condition.run((), world);
+        self.condition.apply_deferred(world);
+        if finished {
+            self.output.set(std::mem::take(&mut self.events));
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::{once, wait};
     use crate::prelude::{Either, Pipe, Reactor, Then};
     use crate::tests::test_app;
     use bevy::app::{Startup, Update};
-    use bevy::prelude::{Commands, EventWriter, Events, In};
+    use bevy::prelude::{Commands, Event, EventWriter, Events, In, Res, ResMut};
     use bevy_test_helper::event::{DirectEvents, TestEvent1, TestEvent2};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
@@ -162,4 +288,77 @@ mod tests {
         let mut er = app.resource_mut::<Events<TestEvent2>>().get_cursor();
         app.assert_event_comes(&mut er);
     }
+
+    #[test]
+    fn read_with_ignores_unmatched_events() {
+        #[derive(Event, Clone)]
+        struct Num(usize);
+
+        let mut app = test_app();
+        app.add_event::<Num>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::event::read_with(|Num(num)| *num == 2)
+                    .pipe(once::run(|In(Num(num)): In<Num>, mut count: ResMut<Count>| {
+                        count.0 = num;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.world_mut().send_event(Num(1));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().send_event(Num(1));
+        app.world_mut().send_event(Num(2));
+        app.world_mut().send_event(Num(3));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn read_with_does_not_consume_events() {
+        #[derive(Event, Clone)]
+        struct Num(usize);
+
+        let mut app = test_app();
+        app.add_event::<Num>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::event::read_with(|Num(num)| *num == 2)).await;
+            }));
+        });
+        app.update();
+        app.world_mut().send_event(Num(1));
+        app.world_mut().send_event(Num(2));
+        app.update();
+        let events = app.world().resource::<Events<Num>>();
+        assert_eq!(events.get_cursor().read(events).count(), 2);
+    }
+
+    #[test]
+    fn collect_events_until_condition() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::event::collect_until::<TestEvent1, _, _>(|b: Res<Bool>| **b)
+                    .pipe(once::run(|In(events): In<Vec<TestEvent1>>, mut count: ResMut<Count>| {
+                        count.0 = events.len();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.send(TestEvent1);
+        app.update();
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.send(TestEvent1);
+        app.set_bool(true);
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
 }
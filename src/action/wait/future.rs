@@ -0,0 +1,105 @@
+use crate::prelude::ActionSeed;
+use crate::runner::{CancellationHandlers, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+/// Polls `future` once per tick on the main thread until it is ready, and returns its output.
+///
+/// The future is polled with a waker that does nothing,
+/// so it is suitable for lightweight futures such as channels and oneshots.
+/// Futures driven by a specific async runtime or heavy work should be run with
+/// the actions of the `effect` feature instead.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let future = async { 1 + 1 };
+/// Reactor::schedule(|task| async move{
+///     let output = task.will(Update, wait::future(future)).await;
+///     assert_eq!(output, 2);
+/// });
+/// ```
+#[inline(always)]
+pub fn future<F>(future: F) -> ActionSeed<(), F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: 'static,
+{
+    let future = Mutex::new(future);
+    ActionSeed::new(move |_, output| FutureRunner {
+        future: future
+            .into_inner()
+            .ok()
+            .map(|future| Box::pin(future) as Pin<Box<dyn Future<Output=F::Output>>>),
+        waker: Waker::from(Arc::new(NoopWaker)),
+        output,
+    })
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+struct FutureRunner<O> {
+    future: Option<Pin<Box<dyn Future<Output=O>>>>,
+    waker: Waker,
+    output: Output<O>,
+}
+
+impl<O> Runner for FutureRunner<O> {
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        let Some(future) = self.future.as_mut() else {
+            return RunnerIs::Canceled;
+        };
+        match future.as_mut().poll(&mut Context::from_waker(&self.waker)) {
+            Poll::Ready(o) => {
+                self.future = None;
+                self.output.set(o);
+                RunnerIs::Completed
+            }
+            Poll::Pending => RunnerIs::Running,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use futures::channel::oneshot;
+    use std::sync::Mutex;
+
+    #[test]
+    fn wait_until_future_ready() {
+        let mut app = test_app();
+        let (tx, rx) = oneshot::channel::<usize>();
+        let rx = Mutex::new(Some(rx));
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let future = wait::future(rx.lock().unwrap().take().unwrap());
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, future.pipe(once::run(|In(num): In<Result<usize, oneshot::Canceled>>, mut count: ResMut<Count>| {
+                    count.0 = num.unwrap();
+                }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        tx.send(3).unwrap();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+}
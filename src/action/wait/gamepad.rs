@@ -0,0 +1,183 @@
+//! [`wait::gamepad`] creates a task related to waiting to gamepad inputs.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::input::gamepad::{Gamepad, GamepadAxis, GamepadButton};
+use bevy::prelude::{Entity, In, Query, With};
+
+/// Waits until any gamepad is connected, and returns the entity of the gamepad.
+///
+/// If a gamepad is already connected when this action starts, it completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let gamepad: Entity = task.will(Update, wait::gamepad::connected()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn connected() -> ActionSeed<(), Entity> {
+    wait::output(|gamepads: Query<Entity, With<Gamepad>>| {
+        gamepads.iter().next()
+    })
+}
+
+/// Waits until the button has just been pressed on any gamepad, and returns the entity of the gamepad.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let gamepad: Entity = task.will(Update, wait::gamepad::button_pressed().with(GamepadButton::South)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn button_pressed() -> ActionSeed<GamepadButton, Entity> {
+    wait::output(|In(button): In<GamepadButton>, gamepads: Query<(Entity, &Gamepad)>| {
+        gamepads
+            .iter()
+            .find_map(|(entity, gamepad)| gamepad.just_pressed(button).then_some(entity))
+    })
+}
+
+/// Waits until the value of the axis goes beyond the threshold on any gamepad,
+/// and returns the entity of the gamepad.
+///
+/// The input is a pair of the axis and the threshold.
+/// If the threshold is positive, it waits until the value becomes greater than or equal to it,
+/// otherwise until the value becomes less than or equal to it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     // Waits until the left stick is tilted to the right.
+///     task.will(Update, wait::gamepad::axis_beyond().with((GamepadAxis::LeftStickX, 0.5))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn axis_beyond() -> ActionSeed<(GamepadAxis, f32), Entity> {
+    wait::output(|In((axis, threshold)): In<(GamepadAxis, f32)>, gamepads: Query<(Entity, &Gamepad)>| {
+        gamepads
+            .iter()
+            .find_map(|(entity, gamepad)| {
+                let value = gamepad.get(axis)?;
+                let beyond = if 0. <= threshold { threshold <= value } else { value <= threshold };
+                beyond.then_some(entity)
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{First, Startup};
+    use bevy::input::gamepad::{Gamepad, GamepadAxis, GamepadButton};
+    use bevy::prelude::{Commands, Entity, In, Query, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_until_connected() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, wait::gamepad::connected()
+                    .pipe(once::run(|In(entity): In<Entity>, mut count: ResMut<Count>, gamepads: Query<(), With<Gamepad>>| {
+                        if gamepads.contains(entity) {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().spawn(Gamepad::default());
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_button_pressed() {
+        let mut app = test_app();
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(First, wait::gamepad::button_pressed()
+                    .with(GamepadButton::South)
+                    .pipe(once::run(move |In(entity): In<Entity>, mut count: ResMut<Count>| {
+                        if entity == gamepad {
+                            count.increment();
+                        }
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        press(&mut app, gamepad, GamepadButton::East);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        press(&mut app, gamepad, GamepadButton::South);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_axis_beyond() {
+        let mut app = test_app();
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, wait::gamepad::axis_beyond()
+                    .with((GamepadAxis::LeftStickX, -0.5))
+                    .pipe(once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        set_axis(&mut app, gamepad, GamepadAxis::LeftStickX, -0.3);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        set_axis(&mut app, gamepad, GamepadAxis::LeftStickX, -0.7);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    fn press(app: &mut bevy::app::App, gamepad: Entity, button: GamepadButton) {
+        app.world_mut()
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .digital_mut()
+            .press(button);
+    }
+
+    fn set_axis(app: &mut bevy::app::App, gamepad: Entity, axis: GamepadAxis, value: f32) {
+        app.world_mut()
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .analog_mut()
+            .set(axis, value);
+    }
+}
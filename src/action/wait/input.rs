@@ -1,9 +1,14 @@
-//! [`wait::input`] creates a task related to waiting to keycode inputs.
+//! [`wait::input`] creates a task related to waiting to button inputs.
+//!
+//! The actions are generic over the type of [`ButtonInput`],
+//! so they can be used with [`KeyCode`](bevy::prelude::KeyCode) and [`MouseButton`](bevy::prelude::MouseButton), etc.
 
 use std::hash::Hash;
+use std::time::Duration;
 
 use bevy::input::ButtonInput;
-use bevy::prelude::{In, Res};
+use bevy::prelude::{In, Local, Res, TimerMode};
+use bevy::time::{Time, Timer};
 use crate::action::seed::ActionSeed;
 use crate::action::wait;
 
@@ -99,6 +104,34 @@ where
     })
 }
 
+/// Waits until any keycode in inputs has just been pressed.
+///
+/// This is useful for "press any key to continue".
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{KeyCode, MouseButton, World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::either(
+///         wait::input::any_just_pressed().with(vec![KeyCode::Space, KeyCode::Enter]),
+///         wait::input::just_pressed().with(MouseButton::Left),
+///     )).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn any_just_pressed<T>() -> ActionSeed<Vec<T>>
+where
+    T: Copy + Eq + Hash + Send + Sync + 'static,
+{
+    wait::until(|In(items): In<Vec<T>>,
+                 input: Res<ButtonInput<T>>| {
+        input.any_just_pressed(items)
+    })
+}
+
 /// Waits keycode has just been released.
 ///
 /// ## Examples
@@ -145,6 +178,61 @@ where
     })
 }
 
+/// Waits until the inputs have been just pressed in order.
+///
+/// Each input must be pressed within `step_timeout` after the previous one;
+/// otherwise, or if a wrong input is pressed, the progress is reset to the beginning.
+/// If the wrong input is the first of the sequence, it counts as the first step of the new progress.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::{KeyCode, World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::input::sequence(Duration::from_millis(500)).with(vec![
+///         KeyCode::ArrowDown,
+///         KeyCode::ArrowRight,
+///         KeyCode::KeyP,
+///     ])).await;
+///     println!("Hadouken!");
+/// });
+/// ```
+#[inline(always)]
+pub fn sequence<T>(step_timeout: Duration) -> ActionSeed<Vec<T>>
+where
+    T: Copy + Eq + Hash + Send + Sync + 'static,
+{
+    wait::until(move |In(items): In<Vec<T>>,
+                      mut progress: Local<(usize, Option<Timer>)>,
+                      input: Res<ButtonInput<T>>,
+                      time: Res<Time>| {
+        let (index, timer) = &mut *progress;
+        if timer.as_mut().is_some_and(|timer| timer.tick(time.delta()).finished()) {
+            *index = 0;
+            *timer = None;
+        }
+        for pressed in input.get_just_pressed() {
+            if items.get(*index) == Some(pressed) {
+                *index += 1;
+            } else if items.first() == Some(pressed) {
+                *index = 1;
+            } else {
+                *index = 0;
+                *timer = None;
+                continue;
+            }
+            if *index == items.len() {
+                return true;
+            }
+            timer.replace(Timer::new(step_timeout, TimerMode::Once));
+        }
+        items.is_empty()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::sequence::Then;
@@ -155,9 +243,11 @@ mod tests {
     use bevy::app::{First, Startup};
     use bevy::input::ButtonInput;
     use bevy::prelude::KeyCode::{KeyA, KeyB, KeyC, KeyD};
-    use bevy::prelude::{Commands, KeyCode, World};
+    use bevy::prelude::{Commands, KeyCode, MouseButton, World};
     use bevy_test_helper::resource::bool::BoolExtension;
+    use bevy::time::TimeUpdateStrategy;
     use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
 
     #[test]
     fn wait_until_pressed_a() {
@@ -295,4 +385,138 @@ mod tests {
         app.update();
         assert!(app.is_bool_true());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn wait_until_any_just_pressed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::any_just_pressed().with(vec![KeyA, KeyB]),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyC);
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn wait_until_mouse_just_pressed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::just_pressed().with(MouseButton::Left),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn wait_until_sequence_pressed() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::sequence(Duration::from_secs(1)).with(vec![KeyA, KeyB]),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyC);
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        // The progress has been reset by `KeyC`.
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().release_all();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn restart_sequence_from_wrong_input_matching_first() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::sequence(Duration::from_secs(1)).with(vec![KeyA, KeyB]),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().release(KeyA);
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn reset_sequence_if_timed_out() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::sequence(Duration::from_millis(300)).with(vec![KeyA, KeyB]),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        for _ in 0..6 {
+            app.update();
+        }
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        assert!(app.is_bool_false());
+    }
+}
@@ -0,0 +1,184 @@
+//! [`wait::message`] creates a task related to waiting to receive events,
+//! where every event is received only once.
+//!
+//! Each reactor has its own cursor for each event type, shared by its [`wait::message`](crate::prelude::wait::message) actions,
+//! so even if the same type is awaited several times in a row,
+//! the same event is never received twice, and events sent in the same frame are received in order.
+//! The cursor starts from the events sent after the first of these actions starts,
+//! and several reactors waiting for the same event type at the same time each receive every event.
+//! Unlike [`wait::event`](crate::prelude::wait::event), the events are not cleared,
+//! so other systems can still read them.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use crate::runner::RunningReactor;
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::{Entity, Event, Events, Mut, Resource, World};
+use bevy::utils::HashMap;
+
+#[derive(Resource)]
+struct MessageCursors<E: Event>(HashMap<Entity, EventCursor<E>>);
+
+impl<E: Event> Default for MessageCursors<E> {
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+/// Waits until the event `E` that has not been received by any other [`wait::message`](crate::prelude::wait::message) action of the reactor is sent.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Hit;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::message::comes::<Hit>()).await;
+///     task.will(Update, wait::message::comes::<Hit>()).await;
+///     println!("hit twice");
+/// });
+/// ```
+#[inline(always)]
+pub fn comes<E>() -> ActionSeed
+where
+    E: Event,
+{
+    wait::until(|world: &mut World| {
+        next_message::<E, _>(world, |_| ()).is_some()
+    })
+}
+
+/// Waits until the event `E` that has not been received by any other [`wait::message`](crate::prelude::wait::message) action of the reactor is sent,
+/// and returns it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Damage(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let Damage(first) = task.will(Update, wait::message::read::<Damage>()).await;
+///     let Damage(second) = task.will(Update, wait::message::read::<Damage>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn read<E>() -> ActionSeed<(), E>
+where
+    E: Event + Clone,
+{
+    wait::output(|world: &mut World| {
+        next_message(world, E::clone)
+    })
+}
+
+fn next_message<E, O>(
+    world: &mut World,
+    f: impl FnOnce(&E) -> O,
+) -> Option<O>
+where
+    E: Event,
+{
+    let reactor = world.get_resource::<RunningReactor>()?.0;
+    world.init_resource::<MessageCursors<E>>();
+    world.resource_scope(|world, mut cursors: Mut<MessageCursors<E>>| {
+        let entities = world.entities();
+        cursors.0.retain(|entity, _| entities.contains(*entity));
+        let events = world.get_resource::<Events<E>>()?;
+        cursors.0
+            .entry(reactor)
+            .or_insert_with(|| events.get_cursor_current())
+            .read(events)
+            .next()
+            .map(f)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::event::{DirectEvents, TestEvent1};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn never_receive_same_event_twice() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::message::comes::<TestEvent1>()
+                    .then(increment_count())
+                    .then(wait::message::comes::<TestEvent1>())
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn receive_events_sent_in_same_frame_in_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::message::read::<TestEvent1>()
+                    .then(increment_count())
+                    .then(wait::message::read::<TestEvent1>())
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.send(TestEvent1);
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn every_reactor_receives_event() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            for _ in 0..2 {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, wait::message::comes::<TestEvent1>().then(increment_count())).await;
+                }));
+            }
+        });
+        app.update();
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn ignore_events_sent_before_waiting() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::message::comes::<TestEvent1>().then(increment_count())).await;
+            }));
+        });
+        app.send(TestEvent1);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+    }
+}
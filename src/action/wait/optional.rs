@@ -0,0 +1,154 @@
+use crate::action::Action;
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{IntoSystem, System, World};
+
+/// Waits until the action is completed or `abort_condition` returns true.
+///
+/// The output is `Some` with the output of the action if it completed,
+/// or `None` if `abort_condition` returned true first.
+/// If both happen in the same frame, the completion of the action takes precedence.
+///
+/// The action is dropped when aborted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Answer(usize);
+///
+/// struct Quiz;
+///
+/// Reactor::schedule(|task| async move{
+///     let answer: Option<Answer> = task.will(Update, wait::optional(
+///         wait::event::read::<Answer>(),
+///         switch_is_off::<Quiz>,
+///     )).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn optional<I, O, Sys, M>(
+    action: impl Into<Action<I, O>> + 'static,
+    abort_condition: Sys,
+) -> Action<I, Option<O>>
+where
+    I: 'static,
+    O: 'static,
+    Sys: IntoSystem<(), bool, M> + Send + Sync + 'static,
+{
+    let Action(input, seed) = action.into();
+    ActionSeed::new(move |input: I, output| {
+        let o1 = Output::default();
+        OptionalRunner {
+            r1: seed.with(input).create_runner(o1.clone()),
+            o1,
+            abort_condition: IntoSystem::into_system(abort_condition),
+            init: false,
+            output,
+        }
+    })
+        .with(input)
+}
+
+struct OptionalRunner<O, Sys> {
+    r1: BoxedRunner,
+    o1: Output<O>,
+    abort_condition: Sys,
+    init: bool,
+    output: Output<Option<O>>,
+}
+
+impl<O, Sys> Runner for OptionalRunner<O, Sys>
+where
+    O: 'static,
+    Sys: System<In=(), Out=bool>,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.r1.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Running => {}
+            RunnerIs::Completed => {
+                let o = self.o1.take().expect("An output value hasn't been set!!!");
+                self.output.set(Some(o));
+                return RunnerIs::Completed;
+            }
+        }
+
+        if !self.init {
+            self.abort_condition.initialize(world);
+            self.init = true;
+        }
+        let abort = self.abort_condition.run((), world);
+        self.abort_condition.apply_deferred(world);
+        if abort {
+            self.output.set(None);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Res, ResMut};
+    use bevy_test_helper::event::{DirectEvents, TestEvent1};
+    use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn some_if_action_completed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::optional(
+                    wait::event::read::<TestEvent1>(),
+                    |b: Res<Bool>| **b,
+                )
+                    .pipe(once::run(|In(event): In<Option<TestEvent1>>, mut count: ResMut<Count>| {
+                        if event.is_some() {
+                            count.increment();
+                        }
+                    }))
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn none_if_aborted() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::optional(
+                    wait::event::read::<TestEvent1>(),
+                    |b: Res<Bool>| **b,
+                )
+                    .pipe(once::run(|In(event): In<Option<TestEvent1>>, mut count: ResMut<Count>| {
+                        if event.is_none() {
+                            count.increment();
+                        }
+                    }))
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.set_bool(true);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::action::Action;
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::{Resource, World};
+use std::marker::PhantomData;
+
+/// The resource holding the progress published by [`wait::progress`](crate::prelude::wait::progress).
+///
+/// The value is normalized in the range `0.0..=1.0`.
+#[derive(Debug, PartialEq)]
+pub struct Progress<M> {
+    value: f32,
+    _m: PhantomData<M>,
+}
+
+impl<M> Resource for Progress<M>
+where
+    M: Send + Sync + 'static,
+{}
+
+impl<M> Progress<M>
+where
+    M: Send + Sync + 'static,
+{
+    /// Creates new progress with the value, clamped to `0.0..=1.0`.
+    #[inline(always)]
+    pub fn new(value: f32) -> Progress<M> {
+        Self {
+            value: value.clamp(0., 1.),
+            _m: PhantomData,
+        }
+    }
+
+    /// Returns the progress value in the range `0.0..=1.0`.
+    #[inline(always)]
+    pub const fn get(&self) -> f32 {
+        self.value
+    }
+
+    /// Returns true if the progress has reached `1.0`.
+    #[inline(always)]
+    pub fn is_completed(&self) -> bool {
+        1. <= self.value
+    }
+}
+
+/// Runs the action, publishing the value returned by `measure` into [`Progress<M>`] every tick.
+///
+/// The value is clamped to `0.0..=1.0`, and set to `1.0` when the action completes.
+/// This allows ui to bind to the resource while the reactor simply awaits the action.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Loaded(usize);
+///
+/// struct Loading;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::progress::<Loading, _, _>(
+///         |world: &World| world.resource::<Loaded>().0 as f32 / 10.,
+///         wait::until(|loaded: Res<Loaded>| loaded.0 == 10),
+///     )).await;
+/// });
+/// ```
+pub fn progress<M, I, O>(
+    measure: impl FnMut(&World) -> f32 + Send + Sync + 'static,
+    action: impl Into<Action<I, O>> + 'static,
+) -> Action<I, O>
+where
+    M: Send + Sync + 'static,
+    I: 'static,
+    O: 'static,
+{
+    let Action(input, seed) = action.into();
+    ActionSeed::new(move |input: I, output| ProgressRunner::<M, _> {
+        r1: seed.with(input).create_runner(output),
+        measure,
+        _m: PhantomData,
+    })
+        .with(input)
+}
+
+struct ProgressRunner<M, F> {
+    r1: BoxedRunner,
+    measure: F,
+    _m: PhantomData<M>,
+}
+
+impl<M, F> Runner for ProgressRunner<M, F>
+where
+    M: Send + Sync + 'static,
+    F: FnMut(&World) -> f32 + 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let value = (self.measure)(world);
+        world.insert_resource(Progress::<M>::new(value));
+        let status = self.r1.run(world, token);
+        if status.is_completed() {
+            world.insert_resource(Progress::<M>::new(1.));
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Progress, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Res, World};
+    use bevy_test_helper::resource::count::Count;
+
+    struct Loading;
+
+    fn progress(app: &bevy::app::App) -> f32 {
+        app.world().resource::<Progress<Loading>>().get()
+    }
+
+    #[test]
+    fn publish_progress_every_tick() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::progress::<Loading, _, _>(
+                    |world: &World| world.resource::<Count>().0 as f32 / 4.,
+                    wait::until(|count: Res<Count>| count.0 == 4),
+                )).await;
+            }));
+        });
+        app.update();
+        assert_eq!(progress(&app), 0.);
+        app.world_mut().resource_mut::<Count>().0 = 1;
+        app.update();
+        assert_eq!(progress(&app), 0.25);
+        app.world_mut().resource_mut::<Count>().0 = 4;
+        app.update();
+        assert_eq!(progress(&app), 1.);
+    }
+}
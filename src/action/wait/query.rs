@@ -0,0 +1,115 @@
+//! [`wait::query`] creates a task related to waiting for queries to match.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::{Entity, Query};
+
+/// Waits until any entity matches the query filter `F`, and returns the entity.
+///
+/// If multiple entities match, one of them is returned.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(Component)]
+/// struct Dead;
+///
+/// Reactor::schedule(|task| async move{
+///     let player: Entity = task.will(Update, wait::query::matches::<(With<Player>, With<Dead>)>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn matches<F>() -> ActionSeed<(), Entity>
+where
+    F: QueryFilter + 'static,
+{
+    wait::output(|query: Query<Entity, F>| {
+        query.iter().next()
+    })
+}
+
+/// Waits until exactly one entity matches the query filter `F`, and returns the entity.
+///
+/// While no entity or more than one entity matches, this keeps waiting.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct MainCamera;
+///
+/// Reactor::schedule(|task| async move{
+///     let camera: Entity = task.will(Update, wait::query::single_matches::<With<MainCamera>>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn single_matches<F>() -> ActionSeed<(), Entity>
+where
+    F: QueryFilter + 'static,
+{
+    wait::output(|query: Query<Entity, F>| {
+        query.get_single().ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Entity, In, With};
+
+    #[derive(Component)]
+    struct Target;
+
+    #[derive(Component)]
+    struct Detected;
+
+    #[test]
+    fn wait_until_matches() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::query::matches::<With<Target>>().pipe(once::run(|In(entity): In<Entity>, mut commands: Commands| {
+                    commands.entity(entity).insert(Detected);
+                }))).await;
+            }));
+        });
+        app.update();
+        let entity = app.world_mut().spawn(Target).id();
+        app.update();
+        assert!(app.world().entity(entity).contains::<Detected>());
+    }
+
+    #[test]
+    fn single_matches_waits_while_multiple_match() {
+        let mut app = test_app();
+        let e1 = app.world_mut().spawn(Target).id();
+        let e2 = app.world_mut().spawn(Target).id();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::query::single_matches::<With<Target>>().pipe(once::run(|In(entity): In<Entity>, mut commands: Commands| {
+                    commands.entity(entity).insert(Detected);
+                }))).await;
+            }));
+        });
+        app.update();
+        assert!(!app.world().entity(e1).contains::<Detected>());
+        assert!(!app.world().entity(e2).contains::<Detected>());
+
+        app.world_mut().entity_mut(e2).remove::<Target>();
+        app.update();
+        assert!(app.world().entity(e1).contains::<Detected>());
+    }
+}
@@ -0,0 +1,218 @@
+//! [`wait::res`] creates a task related to waiting for resources.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::ecs::component::Tick;
+use bevy::prelude::{DetectChanges, Local, Res, Resource};
+
+/// Waits until the resource `R` exists, and returns a clone of it.
+///
+/// If the resource already exists when this action starts, it completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Connection(String);
+///
+/// Reactor::schedule(|task| async move{
+///     let Connection(address) = task.will(Update, wait::res::inserted::<Connection>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn inserted<R>() -> ActionSeed<(), R>
+where
+    R: Resource + Clone,
+{
+    wait::output(|resource: Option<Res<R>>| {
+        resource.map(|resource| resource.clone())
+    })
+}
+
+/// Waits until the resource `R` is changed, and returns a clone of the new value.
+///
+/// Changes are detected by change detection rather than by comparing values,
+/// so mutably dereferencing the resource counts as a change even if the value stays the same.
+/// Inserting the resource also counts as a change.
+/// Only the changes made after this action starts are detected.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Score(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let Score(score) = task.will(Update, wait::res::changed::<Score>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn changed<R>() -> ActionSeed<(), R>
+where
+    R: Resource + Clone,
+{
+    changed_with(|_: &R| true)
+}
+
+/// Waits until the resource `R` is changed and the new value satisfies `predicate`,
+/// and returns a clone of the new value.
+///
+/// This is similar to [`wait::res::changed`], except that changes whose values don't satisfy `predicate` are ignored.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Score(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::res::changed_with(|Score(score): &Score| 100 <= *score)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn changed_with<R>(predicate: impl Fn(&R) -> bool + Send + Sync + 'static) -> ActionSeed<(), R>
+where
+    R: Resource + Clone,
+{
+    wait::output(move |mut last_changed: Local<Option<Option<Tick>>>, resource: Option<Res<R>>| {
+        let tick = resource.as_ref().map(|resource| resource.last_changed());
+        let previous = last_changed.replace(tick)?;
+        let resource = resource?;
+        (previous != tick && predicate(&resource)).then(|| resource.clone())
+    })
+}
+
+/// Waits until the resource `R` is removed.
+///
+/// If the resource doesn't exist when this action starts,
+/// it waits until the resource is inserted and then removed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Connection(String);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::res::inserted::<Connection>()).await;
+///     task.will(Update, wait::res::removed::<Connection>()).await;
+///     println!("disconnected");
+/// });
+/// ```
+#[inline(always)]
+pub fn removed<R>() -> ActionSeed
+where
+    R: Resource,
+{
+    wait::until(|mut existed: Local<bool>, resource: Option<Res<R>>| {
+        if resource.is_some() {
+            *existed = true;
+            false
+        } else {
+            *existed
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Clone)]
+    struct Num(usize);
+
+    #[test]
+    fn wait_until_inserted() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::inserted::<Num>().pipe(once::run(|In(Num(num)): In<Num>, mut count: ResMut<Count>| {
+                    count.0 = num;
+                }))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.insert_resource(Num(3));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn wait_until_changed() {
+        let mut app = test_app();
+        app.insert_resource(Num(1));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::changed::<Num>().pipe(once::run(|In(Num(num)): In<Num>, mut count: ResMut<Count>| {
+                    count.0 = num;
+                }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<Num>().0 = 2;
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn ignore_changes_not_satisfying_predicate() {
+        let mut app = test_app();
+        app.insert_resource(Num(1));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::changed_with(|Num(num): &Num| 3 <= *num).pipe(once::run(|In(Num(num)): In<Num>, mut count: ResMut<Count>| {
+                    count.0 = num;
+                }))).await;
+            }));
+        });
+        app.update();
+        app.resource_mut::<Num>().0 = 2;
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<Num>().0 = 3;
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn wait_until_removed_after_inserted() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::removed::<Num>().then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Num(1));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().remove_resource::<Num>();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
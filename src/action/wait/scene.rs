@@ -0,0 +1,97 @@
+//! [`wait::scene`] creates a task related to waiting for scene instances.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::hierarchy::Parent;
+use bevy::prelude::{Entity, In, Query, Res};
+use bevy::scene::{InstanceId, SceneSpawner};
+use bevy::utils::HashSet;
+
+/// Waits until the scene instance with the passed [`InstanceId`] is fully spawned,
+/// and returns its root entities.
+///
+/// The root entities are the entities of the instance whose parent is not part of the instance,
+/// in the order they are listed by [`SceneSpawner::iter_instance_entities`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let instance_id = task.will(Update, once::run(|mut spawner: ResMut<SceneSpawner>, asset_server: Res<AssetServer>| {
+///         spawner.spawn_dynamic(asset_server.load::<DynamicScene>("level.scn.ron"))
+///     })).await;
+///     let roots = task.will(Update, wait::scene::ready().with(instance_id)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn ready() -> ActionSeed<InstanceId, Vec<Entity>> {
+    wait::output(|In(instance_id): In<InstanceId>, spawner: Res<SceneSpawner>, parents: Query<&Parent>| {
+        if !spawner.instance_is_ready(instance_id) {
+            return None;
+        }
+        let entities = spawner
+            .iter_instance_entities(instance_id)
+            .collect::<Vec<_>>();
+        let lookup = entities.iter().copied().collect::<HashSet<_>>();
+        Some(entities
+            .into_iter()
+            .filter(|entity| parents
+                .get(*entity)
+                .is_none_or(|parent| !lookup.contains(&parent.get())))
+            .collect())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::asset::{AssetPlugin, Assets};
+    use bevy::hierarchy::{BuildChildren, Children, Parent};
+    use bevy::prelude::{Commands, Component, Entity, In, ReflectComponent, ResMut, World};
+    use bevy::reflect::Reflect;
+    use bevy::scene::{Scene, ScenePlugin, SceneSpawner};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Marker;
+
+    #[test]
+    fn output_root_entities_after_ready() {
+        let mut app = test_app();
+        app
+            .add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<Marker>()
+            .register_type::<Parent>()
+            .register_type::<Children>();
+
+        let mut scene_world = World::new();
+        let root = scene_world.spawn(Marker).id();
+        scene_world.spawn(Marker).set_parent(root);
+        scene_world.spawn(Marker);
+        let scene = app.world_mut().resource_mut::<Assets<Scene>>().add(Scene::new(scene_world));
+        let instance_id = app.world_mut().resource_mut::<SceneSpawner>().spawn(scene);
+
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::scene::ready()
+                    .with(instance_id)
+                    .pipe(once::run(|In(roots): In<Vec<Entity>>, mut count: ResMut<Count>| {
+                        count.0 = roots.len();
+                    })),
+                ).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
+}
@@ -0,0 +1,190 @@
+use crate::action::Action;
+use crate::prelude::{ActionSeed, BoxedRunner, RunnerIs};
+use crate::runner::{CancellationHandlers, Output, Runner};
+use bevy::prelude::World;
+use std::convert::Infallible;
+
+/// This enum represents the result of [`select!`](crate::select).
+///
+/// Each variant corresponds to the action passed in the same position,
+/// and the unused type parameters default to [`Infallible`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Selected<A, B, C = Infallible, D = Infallible, E = Infallible, F = Infallible> {
+    /// The output of the first action.
+    A(A),
+    /// The output of the second action.
+    B(B),
+    /// The output of the third action.
+    C(C),
+    /// The output of the fourth action.
+    D(D),
+    /// The output of the fifth action.
+    E(E),
+    /// The output of the sixth action.
+    F(F),
+}
+
+/// Waits until one of the actions is completed,
+/// and returns [`Selected`] holding the output of the completed action.
+///
+/// Unlike [`wait::any`](crate::prelude::wait::any), each action can have a different output type.
+/// It accepts 2 to 6 actions, and the remaining actions are dropped as soon as one of them completes.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::select;
+///
+/// #[derive(Event, Clone)]
+/// struct Damage(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     match task.will(Update, select![
+///         wait::event::read::<Damage>(),
+///         wait::input::just_pressed().with(KeyCode::Escape),
+///         delay::frames().with(60),
+///     ]).await {
+///         Selected::A(Damage(damage)) => println!("damaged {damage}"),
+///         Selected::B(_) => println!("escaped"),
+///         _ => println!("timed out"),
+///     }
+/// });
+/// ```
+#[macro_export]
+macro_rules! select {
+    ($a1: expr, $a2: expr $(,)?) => {
+        $crate::prelude::wait::select2($a1.into(), $a2.into())
+    };
+    ($a1: expr, $a2: expr, $a3: expr $(,)?) => {
+        $crate::prelude::wait::select3($a1.into(), $a2.into(), $a3.into())
+    };
+    ($a1: expr, $a2: expr, $a3: expr, $a4: expr $(,)?) => {
+        $crate::prelude::wait::select4($a1.into(), $a2.into(), $a3.into(), $a4.into())
+    };
+    ($a1: expr, $a2: expr, $a3: expr, $a4: expr, $a5: expr $(,)?) => {
+        $crate::prelude::wait::select5($a1.into(), $a2.into(), $a3.into(), $a4.into(), $a5.into())
+    };
+    ($a1: expr, $a2: expr, $a3: expr, $a4: expr, $a5: expr, $a6: expr $(,)?) => {
+        $crate::prelude::wait::select6($a1.into(), $a2.into(), $a3.into(), $a4.into(), $a5.into(), $a6.into())
+    };
+}
+
+macro_rules! select_fn {
+    ($fn_name: ident, $(($action: ident, $seed: ident, $input: ident, $variant: ident, $I: ident, $O: ident)),+ $(,)?) => {
+        #[doc(hidden)]
+        pub fn $fn_name<$($I, $O,)+>($($action: Action<$I, $O>,)+) -> Action<($($I,)+), Selected<$($O,)+>>
+        where
+            $($I: 'static, $O: 'static,)+
+        {
+            $(let Action($input, $seed) = $action;)+
+            ActionSeed::new(move |($($input,)+): ($($I,)+), output| {
+                SelectRunner {
+                    branches: vec![$({
+                        let o = Output::default();
+                        branch($seed.with($input).create_runner(o.clone()), o, Selected::$variant)
+                    },)+],
+                    output,
+                }
+            })
+                .with(($($input,)+))
+        }
+    };
+}
+
+select_fn!(select2, (a1, s1, i1, A, I1, O1), (a2, s2, i2, B, I2, O2));
+select_fn!(select3, (a1, s1, i1, A, I1, O1), (a2, s2, i2, B, I2, O2), (a3, s3, i3, C, I3, O3));
+select_fn!(select4, (a1, s1, i1, A, I1, O1), (a2, s2, i2, B, I2, O2), (a3, s3, i3, C, I3, O3), (a4, s4, i4, D, I4, O4));
+select_fn!(select5, (a1, s1, i1, A, I1, O1), (a2, s2, i2, B, I2, O2), (a3, s3, i3, C, I3, O3), (a4, s4, i4, D, I4, O4), (a5, s5, i5, E, I5, O5));
+select_fn!(select6, (a1, s1, i1, A, I1, O1), (a2, s2, i2, B, I2, O2), (a3, s3, i3, C, I3, O3), (a4, s4, i4, D, I4, O4), (a5, s5, i5, E, I5, O5), (a6, s6, i6, F, I6, O6));
+
+type Branch<S> = (BoxedRunner, Box<dyn FnMut() -> Option<S>>);
+
+#[inline]
+fn branch<O, S>(runner: BoxedRunner, o: Output<O>, f: impl Fn(O) -> S + 'static) -> Branch<S>
+where
+    O: 'static,
+{
+    (runner, Box::new(move || o.take().map(&f)))
+}
+
+struct SelectRunner<S> {
+    branches: Vec<Branch<S>>,
+    output: Output<S>,
+}
+
+impl<S> Runner for SelectRunner<S> {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        for (runner, take) in self.branches.iter_mut() {
+            match runner.run(world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => continue,
+                RunnerIs::Completed => {
+                    let selected = take().expect("An output value hasn't been set!!!");
+                    self.branches.clear();
+                    self.output.set(selected);
+                    return RunnerIs::Completed;
+                }
+            }
+        }
+        RunnerIs::Running
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait::Selected;
+    use crate::action::{delay, once, wait};
+    use crate::prelude::Reactor;
+    use crate::select;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn select_second() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let selected = task.will(Update, select![
+                    wait::until(|| false),
+                    once::run(|| 3),
+                    delay::frames().with(1),
+                ]).await;
+                if let Selected::B(num) = selected {
+                    task.will(Update, once::run(move |mut count: ResMut<Count>| {
+                        count.0 = num;
+                    })).await;
+                }
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn select_with_inputs() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let selected = task.will(Update, select![
+                    delay::frames().with(3),
+                    delay::frames().with(1),
+                ]).await;
+                if selected == Selected::B(()) {
+                    task.will(Update, once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })).await;
+                }
+            }));
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+}
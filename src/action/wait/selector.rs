@@ -0,0 +1,77 @@
+//! [`wait::selector`] creates a task related to waiting [`Selector`]
+
+use bevy::prelude::{In, Res};
+use crate::action::selector::Selector;
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+
+/// Waits until the selector becomes the state passed as the input.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Door;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum DoorState{
+///     Opened,
+///     Closed,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::selector::becomes::<Door, _>().with(DoorState::Opened)).await;
+/// });
+/// ```
+#[inline]
+pub fn becomes<M, S>() -> ActionSeed<S>
+    where
+        M: Send + Sync + 'static,
+        S: PartialEq + Clone + Send + Sync + 'static
+{
+    wait::until(|In(state): In<S>, selector: Option<Res<Selector<M, S>>>| {
+        selector.is_some_and(|selector| selector.is(&state))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::Startup;
+    use bevy::prelude::{Commands, Update};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use crate::action::wait;
+    use crate::prelude::{Selector, Then};
+    use crate::reactor::Reactor;
+    use crate::tests::{increment_count, test_app};
+
+    struct T;
+
+    #[derive(Clone, PartialEq)]
+    enum S {
+        A,
+        B,
+    }
+
+    #[test]
+    fn wait_selector_becomes() {
+        let mut app = test_app();
+        app.insert_resource(Selector::<T, S>::new(S::A));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::selector::becomes::<T, _>()
+                    .with(S::B)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<Selector<T, S>>().set(S::B);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -1,6 +1,8 @@
 //! [`wait::state`] creates a task related to waiting to state update.
 
-use bevy::prelude::{In, Res, State, States};
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::{Events, In, Local, Res, State, States};
+use bevy::state::state::StateTransitionEvent;
 use crate::action::wait;
 use crate::prelude::ActionSeed;
 
@@ -34,14 +36,100 @@ where
     })
 }
 
+/// Waits until the state transitions into the specified state.
+///
+/// Unlike [`wait::state::becomes`], this doesn't complete if the state is already the specified one
+/// when this action starts; it waits for the next transition into it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{States, World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(States, Eq, PartialEq, Copy, Clone, Hash, Default, Debug)]
+/// enum GameState{
+///     #[default]
+///     Loading,
+///     Playing
+/// }
+///
+/// Reactor::schedule(|task| async move {
+///     task.will(Update, wait::state::enters().with(GameState::Playing)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn enters<S>() -> ActionSeed<S>
+where
+    S: States + 'static,
+{
+    wait::until(move |In(expect): In<S>,
+                      mut cursor: Local<Option<EventCursor<StateTransitionEvent<S>>>>,
+                      events: Res<Events<StateTransitionEvent<S>>>| {
+        read_transitions(&mut cursor, &events)
+            .iter()
+            .any(|transition| transition.entered.as_ref() == Some(&expect))
+    })
+}
+
+/// Waits until the state transitions out of the specified state.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{States, World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(States, Eq, PartialEq, Copy, Clone, Hash, Default, Debug)]
+/// enum GameState{
+///     #[default]
+///     Loading,
+///     Playing
+/// }
+///
+/// Reactor::schedule(|task| async move {
+///     task.will(Update, wait::state::exits().with(GameState::Loading)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn exits<S>() -> ActionSeed<S>
+where
+    S: States + 'static,
+{
+    wait::until(move |In(expect): In<S>,
+                      mut cursor: Local<Option<EventCursor<StateTransitionEvent<S>>>>,
+                      events: Res<Events<StateTransitionEvent<S>>>| {
+        read_transitions(&mut cursor, &events)
+            .iter()
+            .any(|transition| transition.exited.as_ref() == Some(&expect))
+    })
+}
+
+fn read_transitions<'a, S>(
+    cursor: &'a mut Option<EventCursor<StateTransitionEvent<S>>>,
+    events: &'a Events<StateTransitionEvent<S>>,
+) -> Vec<&'a StateTransitionEvent<S>>
+where
+    S: States,
+{
+    if let Some(cursor) = cursor.as_mut() {
+        cursor.read(events).collect()
+    } else {
+        let transitions = events.iter_current_update_events().collect();
+        cursor.replace(events.get_cursor_current());
+        transitions
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use bevy::app::{AppExit, First, Startup, Update};
-    use bevy::prelude::{AppExtStates, Commands, States};
+    use bevy::prelude::{AppExtStates, Commands, NextState, States};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
 
     use crate::prelude::*;
-    use crate::tests::test_app;
+    use crate::tests::{increment_count, test_app};
 
     #[derive(States, Eq, PartialEq, Default, Copy, Clone, Hash, Debug)]
     enum TestState {
@@ -68,4 +156,44 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn wait_until_enters_phase2() {
+        let mut app = test_app();
+        app
+            .init_state::<TestState>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, wait::state::enters().with(TestState::Phase2)
+                        .then(increment_count()),
+                    ).await;
+                }));
+            });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<NextState<TestState>>().set(TestState::Phase2);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_exits_phase1() {
+        let mut app = test_app();
+        app
+            .init_state::<TestState>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, wait::state::exits().with(TestState::Phase1)
+                        .then(increment_count()),
+                    ).await;
+                }));
+            });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<NextState<TestState>>().set(TestState::Phase2);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
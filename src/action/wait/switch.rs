@@ -1,7 +1,7 @@
 //! [`wait::switch`] creates a task related to waiting [`Switch`]
 
-use bevy::prelude::Res;
-use crate::action::switch::Switch;
+use bevy::prelude::{Entity, In, Query, Res};
+use crate::action::switch::{Switch, SwitchComponent, SwitchTuple};
 use crate::action::wait;
 use crate::prelude::ActionSeed;
 
@@ -52,4 +52,127 @@ pub fn off<M>() -> ActionSeed
     wait::until(|switch: Option<Res<Switch<M>>>| {
         switch.is_some_and(|s| s.is_off())
     })
-}
\ No newline at end of file
+}
+
+/// Waits until the switch carrying the payload of type `T` turned on with the payload,
+/// and returns a clone of the payload.
+///
+/// The payload is set by [`Switch::set_on_with`] or [`once::switch::on_with`](crate::prelude::once::switch::on_with),
+/// and is left in the switch so that other systems can still read it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct LoadLevel;
+///
+/// Reactor::schedule(|task| async move{
+///     let level: usize = task.will(Update, wait::switch::on_with::<LoadLevel, usize>()).await;
+/// });
+/// ```
+#[inline]
+pub fn on_with<M, T>() -> ActionSeed<(), T>
+    where
+        M: Send + Sync + 'static,
+        T: Clone + Send + Sync + 'static
+{
+    wait::output(|switch: Option<Res<Switch<M, T>>>| {
+        switch
+            .filter(|s| s.is_on())
+            .and_then(|s| s.payload().cloned())
+    })
+}
+
+/// Waits until all switches of the tuple `S` are turned on.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct HasKey;
+/// struct DoorUnlocked;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::switch::all_on::<(HasKey, DoorUnlocked)>()).await;
+/// });
+/// ```
+#[inline]
+pub fn all_on<S>() -> ActionSeed
+    where S: SwitchTuple
+{
+    wait::until_world(S::all_on)
+}
+
+/// Waits until any switch of the tuple `S` is turned on.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Win;
+/// struct Lose;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::switch::any_on::<(Win, Lose)>()).await;
+/// });
+/// ```
+#[inline]
+pub fn any_on<S>() -> ActionSeed
+    where S: SwitchTuple
+{
+    wait::until_world(S::any_on)
+}
+
+/// Waits until [`SwitchComponent`] of the entity passed as the input turned on.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Open;
+///
+/// Reactor::schedule(|task| async move{
+///     let door = task.will(Update, once::entity::spawn().with(SwitchComponent::<Open>::new(false))).await;
+///     task.will(Update, wait::switch::on_for::<Open>().with(door)).await;
+/// });
+/// ```
+#[inline]
+pub fn on_for<M>() -> ActionSeed<Entity>
+    where M: Send + Sync + 'static
+{
+    wait::until(|In(entity): In<Entity>, switches: Query<&SwitchComponent<M>>| {
+        switches.get(entity).is_ok_and(|s| s.is_on())
+    })
+}
+
+/// Waits until [`SwitchComponent`] of the entity passed as the input turned off.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// struct Open;
+///
+/// Reactor::schedule(|task| async move{
+///     let door = task.will(Update, once::entity::spawn().with(SwitchComponent::<Open>::new(true))).await;
+///     task.will(Update, wait::switch::off_for::<Open>().with(door)).await;
+/// });
+/// ```
+#[inline]
+pub fn off_for<M>() -> ActionSeed<Entity>
+    where M: Send + Sync + 'static
+{
+    wait::until(|In(entity): In<Entity>, switches: Query<&SwitchComponent<M>>| {
+        switches.get(entity).is_ok_and(|s| s.is_off())
+    })
+}
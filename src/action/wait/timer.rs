@@ -0,0 +1,195 @@
+//! [`wait::timer`] creates a task that waits for a [`Timer`] stored in a resource or a component.
+//!
+//! The timer is ticked by the action itself, so the reactor and other systems share one timer
+//! which can be inspected or modified from outside, e.g. to reset or pause it.
+//! Other systems should not tick the same timer, otherwise it advances twice as fast.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::prelude::{Component, Entity, In, Query, Res, ResMut, Resource};
+use bevy::time::{Time, Timer};
+use std::ops::DerefMut;
+
+/// Ticks the [`Timer`] of the resource `R` every frame and waits until it has finished.
+///
+/// If the resource does not exist, it waits until it is inserted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Deref, DerefMut)]
+/// struct Cooldown(Timer);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::timer::finished::<Cooldown>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn finished<R>() -> ActionSeed
+where
+    R: Resource + DerefMut<Target=Timer>,
+{
+    wait::until(|timer: Option<ResMut<R>>, time: Res<Time>| {
+        timer.is_some_and(|mut timer| timer.tick(time.delta()).finished())
+    })
+}
+
+/// Ticks the [`Timer`] of the component `C` attached to the passed entity every frame
+/// and waits until it has finished.
+///
+/// If the entity doesn't have the component, it waits until it is inserted.
+///
+/// This can be used as a delay controlled from outside of the reactor:
+/// other systems can pause, extend, or shorten it while it is running by mutating the timer,
+/// for example with [`Timer::pause`] or [`Timer::set_duration`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component, Deref, DerefMut)]
+/// struct Lifetime(Timer);
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn(Lifetime(Timer::from_seconds(3., TimerMode::Once))).id()
+///     })).await;
+///     task.will(Update, wait::timer::finished_on::<Lifetime>().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn finished_on<C>() -> ActionSeed<Entity>
+where
+    C: Component + DerefMut<Target=Timer>,
+{
+    wait::until(|In(entity): In<Entity>, mut timers: Query<&mut C>, time: Res<Time>| {
+        timers
+            .get_mut(entity)
+            .is_ok_and(|mut timer| timer.tick(time.delta()).finished())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Deref, DerefMut, Resource, TimerMode};
+    use bevy::time::{TimeUpdateStrategy, Timer};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Resource, Component, Deref, DerefMut)]
+    struct TestTimer(Timer);
+
+    impl TestTimer {
+        fn new() -> Self {
+            Self(Timer::new(Duration::from_millis(300), TimerMode::Once))
+        }
+    }
+
+    #[test]
+    fn wait_resource_timer_finished() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.insert_resource(TestTimer::new());
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::timer::finished::<TestTimer>().then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn reset_resource_timer_from_outside() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.insert_resource(TestTimer::new());
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::timer::finished::<TestTimer>().then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.resource_mut::<TestTimer>().pause();
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.resource_mut::<TestTimer>().unpause();
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_component_timer_finished() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let entity = task.will(Update, once::run(|mut commands: Commands| {
+                    commands.spawn(TestTimer::new()).id()
+                })).await;
+                task.will(Update, wait::timer::finished_on::<TestTimer>()
+                    .with(entity)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        for _ in 0..6 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn extend_and_pause_component_timer_from_outside() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let entity = app.world_mut().spawn(TestTimer::new()).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::timer::finished_on::<TestTimer>()
+                    .with(entity)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        {
+            let mut timer = app.world_mut().get_mut::<TestTimer>(entity).unwrap();
+            timer.set_duration(Duration::from_millis(1000));
+            timer.pause();
+        }
+        for _ in 0..20 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.world_mut().get_mut::<TestTimer>(entity).unwrap().unpause();
+        for _ in 0..20 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+}
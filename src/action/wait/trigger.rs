@@ -0,0 +1,248 @@
+use crate::prelude::seed::ActionSeed;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{Output, Runner, RunnerIs};
+use bevy::prelude::{Commands, Entity, Event, Observer, Res, Resource, Trigger, World};
+use std::sync::{Arc, Mutex};
+
+/// Waits until the event `E` is triggered, and returns the event.
+///
+/// An [`Observer`] is spawned on the first tick of this action and despawned when it ends,
+/// so events triggered before the action starts are not observed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Explode;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::trigger::<Explode>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn trigger<E>() -> ActionSeed<(), E>
+where
+    E: Event + Clone,
+{
+    ActionSeed::new(|_, output| TriggerRunner::new(None, output))
+}
+
+/// Waits until the event `E` is triggered targeting the passed entity, and returns the event.
+///
+/// An [`Observer`] watching the entity is spawned on the first tick of this action and
+/// despawned when it ends, so events triggered before the action starts are not observed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Damaged(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let player = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn_empty().id()
+///     })).await;
+///     let Damaged(damage) = task.will(Update, wait::trigger_on::<Damaged>().with(player)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn trigger_on<E>() -> ActionSeed<Entity, E>
+where
+    E: Event + Clone,
+{
+    ActionSeed::new(|target, output| TriggerRunner::new(Some(target), output))
+}
+
+/// The observers spawned by [`TriggerRunner`]s that were dropped before they completed,
+/// such as when they lost the race in [`wait::either`](crate::prelude::wait::either) or their reactor was canceled.
+///
+/// They are despawned in [`Last`](bevy::prelude::Last) by [`despawn_dropped_observers`].
+#[derive(Resource, Clone, Default)]
+pub(crate) struct DroppedObservers(Arc<Mutex<Vec<Entity>>>);
+
+pub(crate) fn despawn_dropped_observers(
+    dropped: Option<Res<DroppedObservers>>,
+    mut commands: Commands,
+) {
+    let Some(dropped) = dropped else {
+        return;
+    };
+    let Ok(mut observers) = dropped.0.lock() else {
+        return;
+    };
+    for observer in observers.drain(..) {
+        commands.queue(move |world: &mut World| {
+            despawn_observer(world, observer);
+        });
+    }
+}
+
+struct TriggerRunner<E> {
+    target: Option<Entity>,
+    observer: Option<Entity>,
+    dropped: DroppedObservers,
+    event: Arc<Mutex<Option<E>>>,
+    output: Output<E>,
+}
+
+impl<E> TriggerRunner<E> {
+    fn new(target: Option<Entity>, output: Output<E>) -> Self {
+        Self {
+            target,
+            observer: None,
+            dropped: DroppedObservers::default(),
+            event: Arc::new(Mutex::new(None)),
+            output,
+        }
+    }
+}
+
+fn despawn_observer(world: &mut World, observer: Entity) {
+    if world.entities().contains(observer) {
+        world.despawn(observer);
+    }
+}
+
+impl<E> Runner for TriggerRunner<E>
+where
+    E: Event + Clone,
+{
+    fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        let Some(observer) = self.observer else {
+            let event = self.event.clone();
+            let mut observer = Observer::new(move |trigger: Trigger<E>| {
+                if let Ok(mut event) = event.lock() {
+                    event.get_or_insert_with(|| trigger.event().clone());
+                }
+            });
+            if let Some(target) = self.target {
+                observer.watch_entity(target);
+            }
+            let observer = world.spawn(observer).id();
+            self.observer.replace(observer);
+            self.dropped = world.get_resource_or_insert_with(DroppedObservers::default).clone();
+            return RunnerIs::Running;
+        };
+
+        let Some(event) = self.event.lock().ok().and_then(|mut event| event.take()) else {
+            return RunnerIs::Running;
+        };
+        despawn_observer(world, observer);
+        self.observer = None;
+        self.output.set(event);
+        RunnerIs::Completed
+    }
+}
+
+impl<E> Drop for TriggerRunner<E> {
+    fn drop(&mut self) {
+        let Some(observer) = self.observer.take() else {
+            return;
+        };
+        if let Ok(mut observers) = self.dropped.0.lock() {
+            observers.push(observer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Observer, ResMut};
+    use bevy_test_helper::event::TestEvent1;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_triggered() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::trigger::<TestEvent1>().pipe(once::run(|In(_): In<TestEvent1>, mut count: ResMut<Count>| {
+                    count.increment();
+                }))).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().trigger(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.world_mut().trigger(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn ignore_event_triggered_on_other_entity() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn_empty().id();
+        let other = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::trigger_on::<TestEvent1>()
+                    .with(target)
+                    .pipe(once::run(|In(_): In<TestEvent1>, mut count: ResMut<Count>| {
+                        count.increment();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.world_mut().trigger_targets(TestEvent1, other);
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().trigger_targets(TestEvent1, target);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn despawn_observer_if_lost_race() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::either(
+                    wait::trigger::<TestEvent1>(),
+                    delay::frames().with(3),
+                )).await;
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        let mut observers = || {
+            app.update();
+            app.world_mut().query::<&Observer>().iter(app.world()).count()
+        };
+        let spawned = observers();
+        for _ in 0..5 {
+            observers();
+        }
+        assert_eq!(observers(), spawned - 1);
+    }
+
+    #[test]
+    fn despawn_observer_if_reactor_despawned() {
+        let mut app = test_app();
+        let reactor = app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, wait::trigger::<TestEvent1>()).await;
+        })).id();
+        let mut observers = |app: &mut bevy::app::App| {
+            app.update();
+            app.world_mut().query::<&Observer>().iter(app.world()).count()
+        };
+        let spawned = observers(&mut app);
+        app.world_mut().despawn(reactor);
+        observers(&mut app);
+        // Both the observer of the runner and the one watching the reactor are despawned.
+        assert_eq!(observers(&mut app), spawned - 2);
+    }
+}
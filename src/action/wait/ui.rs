@@ -0,0 +1,128 @@
+//! [`wait::ui`] creates a task related to waiting for ui [`Interaction`].
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::prelude::{Entity, In, Local, Query};
+use bevy::ui::Interaction;
+
+/// Waits until the [`Interaction`] of the passed ui node entity changes to [`Interaction::Pressed`].
+///
+/// Only the transition is detected; if the node is already pressed when this action starts,
+/// it waits until it is released and pressed again.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let button = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn(Button).id()
+///     })).await;
+///     task.will(Update, wait::ui::clicked().with(button)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn clicked() -> ActionSeed<Entity> {
+    becomes(Interaction::Pressed)
+}
+
+/// Waits until the [`Interaction`] of the passed ui node entity changes to [`Interaction::Hovered`].
+///
+/// Only the transition is detected; if the node is already hovered when this action starts,
+/// it waits until the cursor leaves and enters again.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let button = task.will(Update, once::run(|mut commands: Commands| {
+///         commands.spawn(Button).id()
+///     })).await;
+///     task.will(Update, wait::ui::hovered().with(button)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn hovered() -> ActionSeed<Entity> {
+    becomes(Interaction::Hovered)
+}
+
+fn becomes(expected: Interaction) -> ActionSeed<Entity> {
+    wait::until(move |In(entity): In<Entity>,
+                      mut previous: Local<Option<Interaction>>,
+                      interactions: Query<&Interaction>| {
+        let Ok(interaction) = interactions.get(entity) else {
+            return false;
+        };
+        let started = previous.is_some();
+        let transitioned = previous.replace(*interaction) != Some(expected);
+        started && transitioned && *interaction == expected
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Commands, Entity};
+    use bevy::ui::Interaction;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_until_clicked() {
+        let mut app = test_app();
+        let button = app.world_mut().spawn(Interaction::None).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::ui::clicked().with(button)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        set_interaction(&mut app, button, Interaction::Hovered);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        set_interaction(&mut app, button, Interaction::Pressed);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_hovered_again_if_already_hovered() {
+        let mut app = test_app();
+        let button = app.world_mut().spawn(Interaction::Hovered).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::ui::hovered().with(button)
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        set_interaction(&mut app, button, Interaction::None);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        set_interaction(&mut app, button, Interaction::Hovered);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    fn set_interaction(app: &mut App, button: Entity, interaction: Interaction) {
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = interaction;
+    }
+}
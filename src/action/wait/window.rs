@@ -0,0 +1,219 @@
+//! [`wait::window`] creates a task related to waiting for window events.
+//!
+//! Unlike [`wait::event::read`](crate::prelude::wait::event::read), these actions don't clear the events,
+//! so other systems can still read them.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::{Event, Events, Local, Res};
+use bevy::window::{WindowCloseRequested, WindowFocused, WindowResized};
+
+/// Waits until a window gains focus, and returns the event.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::window::unfocused()).await;
+///     println!("paused");
+///     task.will(Update, wait::window::focused()).await;
+///     println!("resumed");
+/// });
+/// ```
+#[inline(always)]
+pub fn focused() -> ActionSeed<(), WindowFocused> {
+    read_window_event(|event: &WindowFocused| event.focused)
+}
+
+/// Waits until a window loses focus, and returns the event.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::window::unfocused()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn unfocused() -> ActionSeed<(), WindowFocused> {
+    read_window_event(|event: &WindowFocused| !event.focused)
+}
+
+/// Waits until a window is resized, and returns the event.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::window::WindowResized;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let WindowResized { width, height, .. } = task.will(Update, wait::window::resized()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn resized() -> ActionSeed<(), WindowResized> {
+    read_window_event(|_: &WindowResized| true)
+}
+
+/// Waits until the user requests to close a window, and returns the event.
+///
+/// Note that the window is closed by bevy unless `WindowPlugin::close_when_requested` is false.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let request = task.will(Update, wait::window::close_requested()).await;
+///     println!("confirm quit: {:?}", request.window);
+/// });
+/// ```
+#[inline(always)]
+pub fn close_requested() -> ActionSeed<(), WindowCloseRequested> {
+    read_window_event(|_: &WindowCloseRequested| true)
+}
+
+fn read_window_event<E>(predicate: fn(&E) -> bool) -> ActionSeed<(), E>
+where
+    E: Event + Clone,
+{
+    wait::output(move |mut cursor: Local<Option<EventCursor<E>>>, events: Res<Events<E>>| {
+        if cursor.is_none() {
+            if let Some(event) = events.iter_current_update_events().filter(|event| predicate(event)).last() {
+                return Some(event.clone());
+            }
+        }
+        cursor
+            .get_or_insert_with(|| events.get_cursor_current())
+            .read(&events)
+            .filter(|event| predicate(event))
+            .last()
+            .cloned()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Commands, Entity, In, ResMut};
+    use bevy::window::{WindowCloseRequested, WindowFocused, WindowResized};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn window_test_app() -> App {
+        let mut app = test_app();
+        app
+            .add_event::<WindowFocused>()
+            .add_event::<WindowResized>()
+            .add_event::<WindowCloseRequested>();
+        app
+    }
+
+    #[test]
+    fn wait_until_focused() {
+        let mut app = window_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::window::focused()
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().send_event(WindowFocused { window: Entity::PLACEHOLDER, focused: false });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().send_event(WindowFocused { window: Entity::PLACEHOLDER, focused: true });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_unfocused() {
+        let mut app = window_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::window::unfocused()
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.world_mut().send_event(WindowFocused { window: Entity::PLACEHOLDER, focused: true });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().send_event(WindowFocused { window: Entity::PLACEHOLDER, focused: false });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn output_resized_event() {
+        let mut app = window_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::window::resized()
+                    .pipe(once::run(|In(event): In<WindowResized>, mut count: ResMut<Count>| {
+                        count.0 = event.width as usize;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().send_event(WindowResized { window: Entity::PLACEHOLDER, width: 300., height: 200. });
+        app.update();
+        app.assert_resource_eq(Count(300));
+    }
+
+    #[test]
+    fn wait_until_close_requested() {
+        let mut app = window_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::window::close_requested()
+                    .then(increment_count()),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().send_event(WindowCloseRequested { window: Entity::PLACEHOLDER });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn events_remain_readable() {
+        let mut app = window_test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::window::close_requested()).await;
+            }));
+        });
+        app.update();
+        app.world_mut().send_event(WindowCloseRequested { window: Entity::PLACEHOLDER });
+        app.update();
+        assert!(!app.world().resource::<bevy::prelude::Events<WindowCloseRequested>>().is_empty());
+    }
+}
@@ -6,13 +6,14 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::type_complexity)]
 
-use crate::reactor::NativeReactor;
+use crate::reactor::{NativeReactor, ReactorStatus};
+use crate::action::switch::tick_switch_timers;
+use crate::action::wait::despawn_dropped_observers;
 use crate::runner::CallCancellationHandlers;
 use crate::world_ptr::WorldPtr;
-use bevy::app::{App, Last, Plugin, PostStartup};
-use bevy::ecs::system::SystemState;
+use bevy::app::{App, First, Last, Plugin, PostStartup};
 use bevy::hierarchy::DespawnRecursiveExt;
-use bevy::prelude::{Entity, EventReader, IntoSystemConfigs, QueryState, World};
+use bevy::prelude::{Entity, Events, IntoSystemConfigs, QueryState, World};
 
 pub mod action;
 pub mod runner;
@@ -28,18 +29,28 @@ pub mod prelude {
     #[cfg(feature = "effect")]
     pub use crate::action::side_effect::AsyncFunctor;
     pub use crate::{
+        action::finally::Finally,
+        action::gated::gated,
         action::inspect::{inspect, Inspect},
+        action::interrupt::{interrupt_on, Interrupted},
         action::omit::*,
+        action::or_else::{Fallible, OrElse},
         action::pipe::Pipe,
+        action::retry::{retry, retry_with_backoff, Backoff},
         action::seed::ActionSeed,
-        action::sequence::Then,
+        action::sequence::{BoxedAction, Then},
+        action::selector::*,
+        action::skip::{skippable, SkipStep},
         action::switch::*,
+        action::tap::Tap,
+        action::throttle::Throttle,
         action::through::{through, Through},
-        action::wait::Either,
+        action::timeout::{Elapsed, Timeout},
+        action::wait::{Either, Progress, Selected},
         action::Map,
         action::Remake,
         action::*,
-        reactor::Reactor,
+        reactor::{Reactor, ReactorHandle, ReactorStatus},
         runner::*,
         task::ReactorTask,
         FlurxPlugin,
@@ -62,50 +73,76 @@ impl Plugin for FlurxPlugin {
     #[inline]
     fn build(&self, app: &mut App) {
         app
+            .register_type::<ReactorStatus>()
             .add_event::<CallCancellationHandlers>()
             .add_systems(PostStartup, initialize_reactors)
+            .add_systems(First, tick_switch_timers)
             .add_systems(Last, (
                 call_cancel_handlers.run_if(bevy::prelude::on_event::<CallCancellationHandlers>),
                 run_reactors,
+                despawn_dropped_observers,
             ));
     }
 }
 
 fn initialize_reactors(
     world: &mut World,
-    reactors: &mut QueryState<&mut NativeReactor>,
+    reactors: &mut QueryState<(&mut NativeReactor, Option<&mut ReactorStatus>)>,
 ) {
     let world_ptr = WorldPtr::new(world);
-    for mut reactor in reactors.iter_mut(world).filter(|r| !r.initialized) {
+    for (mut reactor, status) in reactors.iter_mut(world).filter(|(r, _)| !r.initialized) {
         reactor.run_sync(world_ptr);
         reactor.initialized = true;
+        if let Some(mut status) = status {
+            status.initialized = true;
+            status.ticks += 1;
+        }
     }
 }
 
 fn call_cancel_handlers(
     world: &mut World,
 ) {
-    let mut event_system_state = SystemState::<EventReader<CallCancellationHandlers>>::new(world);
-    let handlers = event_system_state
-        .get_mut(world)
-        .read()
-        .flat_map(|handler| handler.0.0.values().copied())
+    let handlers = world
+        .resource_mut::<Events<CallCancellationHandlers>>()
+        .drain()
+        .flat_map(|handler| handler.0.0.into_values())
         .collect::<Vec<_>>();
     for handler in handlers {
         handler(world);
     }
 }
 
-fn run_reactors(world: &mut World, reactors: &mut QueryState<(Entity, &mut NativeReactor)>) {
+fn run_reactors(
+    world: &mut World,
+    reactors: &mut QueryState<(Entity, &mut NativeReactor, Option<&mut ReactorStatus>)>,
+) {
+    let canceled = reactors
+        .iter(world)
+        .filter(|(_, reactor, _)| reactor.handle.cancel_requested())
+        .map(|(entity, ..)| entity)
+        .collect::<Vec<_>>();
+    for entity in canceled {
+        world.entity_mut(entity).remove::<NativeReactor>();
+    }
+
     let world_ptr = WorldPtr::new(world);
     let mut entities = Vec::new();
 
-    for (entity, mut reactor) in reactors.iter_mut(world) {
+    for (entity, mut reactor, mut status) in reactors.iter_mut(world) {
         if !reactor.initialized {
             reactor.run_sync(world_ptr);
             reactor.initialized = true;
+            if let Some(status) = status.as_mut() {
+                status.initialized = true;
+                status.ticks += 1;
+            }
+        }
+        let finished = reactor.run_sync(world_ptr);
+        if let Some(status) = status.as_mut() {
+            status.ticks += 1;
         }
-        if reactor.run_sync(world_ptr) {
+        if finished {
             entities.push(entity);
         }
     }
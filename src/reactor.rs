@@ -3,16 +3,19 @@ use crate::task::ReactorTask;
 use crate::world_ptr::WorldPtr;
 use bevy::ecs::component::{ComponentHooks, StorageType};
 use bevy::ecs::world::DeferredWorld;
-use bevy::prelude::{Component, Entity, ReflectComponent};
+use bevy::prelude::{Component, Entity, ReflectComponent, ReflectDefault};
 use bevy::reflect::Reflect;
 use std::future::Future;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 /// [`Reactor`] represents the asynchronous processing flow.
 ///
 /// This structure is created by [`Reactor::schedule`].
 ///
-/// Despawn the entity attached this component if you want to interrupt the processing flow.
+/// Despawn the entity attached this component if you want to interrupt the processing flow,
+/// or use [`ReactorHandle`] to cancel it while keeping the entity.
 ///
 /// After all scheduled processes have completed, the entity attached to this component
 /// and it's children will be despawn.
@@ -24,6 +27,8 @@ where
     Fut: Future + Send + Sync + 'static,
 {
     f: Option<F>,
+    #[reflect(ignore)]
+    handle: ReactorHandle,
     _m: PhantomData<Fut>,
 }
 
@@ -52,9 +57,40 @@ where
     pub fn schedule(f: F) -> Reactor<F, Fut> {
         Self {
             f: Some(f),
+            handle: ReactorHandle::default(),
             _m: PhantomData,
         }
     }
+
+    /// Returns the [`ReactorHandle`] of this reactor.
+    ///
+    /// The same handle is attached to the entity when this reactor is spawned.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct Cutscene(ReactorHandle);
+    ///
+    /// fn spawn_cutscene(mut commands: Commands) {
+    ///     let reactor = Reactor::schedule(|task| async move{
+    ///         task.will(Update, delay::time().with(std::time::Duration::from_secs(10))).await;
+    ///     });
+    ///     commands.insert_resource(Cutscene(reactor.handle()));
+    ///     commands.spawn(reactor);
+    /// }
+    ///
+    /// fn skip_cutscene(cutscene: Res<Cutscene>) {
+    ///     cutscene.0.cancel();
+    /// }
+    /// ```
+    #[inline]
+    pub fn handle(&self) -> ReactorHandle {
+        self.handle.clone()
+    }
 }
 
 impl<F, Fut> Component for Reactor<F, Fut>
@@ -67,7 +103,7 @@ where
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks
             .on_add(|mut world: DeferredWorld, entity: Entity, _| {
-                let f = {
+                let (f, handle) = {
                     let mut entity_mut = world.entity_mut(entity);
                     let Some(mut flow) = entity_mut.get_mut::<Reactor<F, Fut>>() else {
                         return;
@@ -75,21 +111,99 @@ where
                     let Some(f) = flow.f.take() else {
                         return;
                     };
-                    f
+                    (f, flow.handle.clone())
                 };
-                world.commands().entity(entity).insert(NativeReactor::schedule(entity, f));
+                world.commands().entity(entity).insert((
+                    NativeReactor::schedule(entity, f, handle.clone()),
+                    ReactorStatus::default(),
+                    handle,
+                ));
             });
     }
 }
 
+/// The runtime state of a reactor.
+///
+/// It's attached to the entity of each [`Reactor`] and registered to the type registry by [`FlurxPlugin`](crate::FlurxPlugin),
+/// so inspectors such as `bevy-inspector-egui` can display which reactors exist and whether they are progressing.
+#[derive(Component, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[reflect(Component, Default)]
+pub struct ReactorStatus {
+    /// Whether the reactor has been initialized.
+    pub initialized: bool,
+
+    /// The number of times the reactor has been polled.
+    pub ticks: u64,
+}
+
+/// A cloneable handle to cancel a [`Reactor`] and to check whether it has finished.
+///
+/// It's attached to the entity of the reactor, and can also be obtained before spawning by [`Reactor::handle`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Patrol;
+///
+/// fn stop_patrol(reactors: Query<&ReactorHandle, With<Patrol>>) {
+///     for handle in reactors.iter() {
+///         handle.cancel();
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Default, Clone)]
+pub struct ReactorHandle(Arc<ReactorHandleState>);
+
+#[derive(Debug, Default)]
+struct ReactorHandleState {
+    cancel_requested: AtomicBool,
+    finished: AtomicBool,
+    canceled: AtomicBool,
+}
+
+impl ReactorHandle {
+    /// Requests to cancel the reactor.
+    ///
+    /// The reactor is canceled in [`Last`](bevy::prelude::Last) unless it has already finished.
+    /// The running actions are dropped and their cancellation handlers are called,
+    /// but unlike finishing, the entity of the reactor is not despawned.
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.cancel_requested.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if all scheduled processes of the reactor have completed.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.0.finished.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the reactor has been canceled before it finished,
+    /// whether by [`ReactorHandle::cancel`], by despawning its entity, or by an action.
+    #[inline]
+    pub fn is_canceled(&self) -> bool {
+        self.0.canceled.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub(crate) fn cancel_requested(&self) -> bool {
+        self.0.cancel_requested.load(Ordering::Relaxed)
+    }
+}
+
 #[derive(Component)]
 pub(crate) struct NativeReactor {
     pub(crate) scheduler: CoreScheduler<WorldPtr>,
     pub(crate) initialized: bool,
+    pub(crate) handle: ReactorHandle,
 }
 
 impl NativeReactor {
-    fn schedule<F>(entity: Entity, f: impl FnOnce(ReactorTask) -> F + Send + Sync + 'static) -> NativeReactor
+    fn schedule<F>(entity: Entity, f: impl FnOnce(ReactorTask) -> F + Send + Sync + 'static, handle: ReactorHandle) -> NativeReactor
     where
         F: Future + Send + Sync,
     {
@@ -102,6 +216,7 @@ impl NativeReactor {
         Self {
             scheduler,
             initialized: false,
+            handle,
         }
     }
 
@@ -120,19 +235,31 @@ impl NativeReactor {
         {
             pollster::block_on(self.scheduler.run(world));
         }
+        if self.scheduler.finished {
+            self.handle.0.finished.store(true, Ordering::Relaxed);
+        }
         self.scheduler.finished
     }
 }
 
+impl Drop for NativeReactor {
+    fn drop(&mut self) {
+        if !self.handle.is_finished() {
+            self.handle.0.canceled.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::{delay, once, wait};
-    use crate::prelude::Reactor;
+    use crate::prelude::{Finally, Reactor, ReactorHandle, ReactorStatus};
     use crate::reactor::NativeReactor;
     use crate::tests::test_app;
     use bevy::app::{Startup, Update};
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{Commands, Entity, Query, ResMut, Resource, With};
+    use crate::tests::increment_count;
+    use bevy::prelude::{AppTypeRegistry, Commands, Entity, Query, ResMut, Resource, With};
     use bevy_test_helper::resource::DirectResourceControl;
 
     #[derive(Resource, Debug, Default, Eq, PartialEq)]
@@ -213,4 +340,92 @@ mod tests {
         app.assert_resource_eq(Count(2));
         app.assert_resource_eq(Bool2(true));
     }
+
+    #[test]
+    fn update_reactor_status() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(3)).await;
+            }));
+        });
+        app.update();
+        let status = *app
+            .world_mut()
+            .query::<&ReactorStatus>()
+            .single(app.world());
+        assert!(status.initialized);
+
+        app.update();
+        let ticks = app
+            .world_mut()
+            .query::<&ReactorStatus>()
+            .single(app.world())
+            .ticks;
+        assert!(status.ticks < ticks);
+    }
+
+    #[test]
+    fn reactor_status_is_registered() {
+        let app = test_app();
+        assert!(app
+            .world()
+            .resource::<AppTypeRegistry>()
+            .read()
+            .contains(std::any::TypeId::of::<ReactorStatus>()));
+    }
+
+    #[test]
+    fn cancel_reactor_by_handle() {
+        let mut app = test_app();
+        let reactor = Reactor::schedule(|task| async move {
+            task.will(Update, delay::frames().with(100).finally(increment_count())).await;
+        });
+        let handle = reactor.handle();
+        let entity = app.world_mut().spawn(reactor).id();
+        app.update();
+        assert!(!handle.is_canceled());
+
+        handle.cancel();
+        app.update();
+        app.update();
+        assert!(handle.is_canceled());
+        assert!(!handle.is_finished());
+        assert!(app.world().get::<NativeReactor>(entity).is_none());
+        assert!(app.world().entities().contains(entity));
+        app.assert_resource_eq(bevy_test_helper::resource::count::Count(1));
+    }
+
+    #[test]
+    fn handle_is_finished() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(1)).await;
+            }));
+        });
+        app.update();
+        let handle = app
+            .world_mut()
+            .query::<&ReactorHandle>()
+            .single(app.world())
+            .clone();
+        assert!(!handle.is_finished());
+        app.update();
+        assert!(handle.is_finished());
+        assert!(!handle.is_canceled());
+    }
+
+    #[test]
+    fn handle_is_canceled_if_despawned() {
+        let mut app = test_app();
+        let reactor = Reactor::schedule(|task| async move {
+            task.will(Update, delay::frames().with(100)).await;
+        });
+        let handle = reactor.handle();
+        let entity = app.world_mut().spawn(reactor).id();
+        app.update();
+        app.world_mut().despawn(entity);
+        assert!(handle.is_canceled());
+    }
 }
@@ -3,7 +3,7 @@
 use crate::reactor::NativeReactor;
 pub use crate::runner::cancellation_handlers::{CancellationHandlers, CancellationId};
 use bevy::ecs::schedule::ScheduleLabel;
-use bevy::prelude::{Component, Entity, EventWriter, NonSendMut, Observer, OnRemove, Reflect, ReflectComponent, Schedules, Trigger, With, World};
+use bevy::prelude::{Component, Entity, EventWriter, NonSendMut, Observer, OnRemove, Reflect, ReflectComponent, Resource, Schedules, Trigger, With, World};
 pub(crate) use cancellation_handlers::CallCancellationHandlers;
 pub use output::Output;
 use std::marker::PhantomData;
@@ -68,7 +68,10 @@ impl Runner for BoxedRunner {
         if let Some(mut runner) = self.0.take() {
             match runner.run(world, cancellation_handlers) {
                 RunnerIs::Completed => RunnerIs::Completed,
-                RunnerIs::Canceled => RunnerIs::Canceled,
+                RunnerIs::Canceled => {
+                    cancellation_handlers.mark_canceled();
+                    RunnerIs::Canceled
+                }
                 status => {
                     self.0.replace(runner);
                     status
@@ -81,7 +84,7 @@ impl Runner for BoxedRunner {
 }
 
 #[repr(transparent)]
-struct ReactorMap<L: Send + Sync>(Vec<(Entity, Vec<BoxedRunner>, CancellationHandlers)>, PhantomData<L>);
+struct ReactorMap<L: Send + Sync>(Vec<(Entity, Vec<(usize, BoxedRunner)>, CancellationHandlers)>, PhantomData<L>);
 
 impl<L: Send + Sync> Default for ReactorMap<L> {
     fn default() -> Self {
@@ -93,6 +96,17 @@ impl<L: Send + Sync> Default for ReactorMap<L> {
 #[reflect(Component)]
 struct ReactorEntity(Entity);
 
+/// The number of the runners initialized so far by the reactor.
+#[derive(Component, Default)]
+struct ReactorSteps(usize);
+
+/// The entity of the reactor whose runners are currently running,
+/// and the step of the running runner, starting from 1 for the first `task.will` of the reactor.
+///
+/// It exists only while runners are running, so actions can refer to the reactor they belong to.
+#[derive(Resource, Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct RunningReactor(pub(crate) Entity, pub(crate) usize);
+
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 struct ReactorScheduleLabel<Label: ScheduleLabel>(PhantomData<Label>);
@@ -107,6 +121,7 @@ where
     Label: ScheduleLabel,
 {
     observe_remove_reactor::<Label>(entity, world);
+    let runner = (next_step(world, entity), runner);
     if let Some(mut map) = world.get_non_send_resource_mut::<ReactorMap<Label>>() {
         if let Some((_, runners, _)) = map.0.iter_mut().find(|(e, ..)| e == &entity) {
             runners.push(runner);
@@ -125,6 +140,19 @@ where
     }
 }
 
+fn next_step(world: &mut World, entity: Entity) -> usize {
+    let Ok(mut reactor) = world.get_entity_mut(entity) else {
+        return 0;
+    };
+    if let Some(mut steps) = reactor.get_mut::<ReactorSteps>() {
+        steps.0 += 1;
+        steps.0
+    } else {
+        reactor.insert(ReactorSteps(1));
+        1
+    }
+}
+
 fn observer_already_exists<Label: ScheduleLabel>(
     world: &mut World,
     reactor_entity: &Entity,
@@ -148,7 +176,9 @@ fn observe_remove_reactor<Label: ScheduleLabel>(
         let Some(i) = reactor_map.0.iter().position(|(e, ..)| e == &entity) else {
             return;
         };
-        let (.., cancellation_handlers) = reactor_map.0.remove(i);
+        let (_, runners, cancellation_handlers) = reactor_map.0.remove(i);
+        cancellation_handlers.mark_canceled();
+        drop(runners);
         ew.send(CallCancellationHandlers(cancellation_handlers));
     });
     observer.watch_entity(entity);
@@ -166,10 +196,11 @@ fn run_runners<L: Send + Sync + 'static>(world: &mut World) {
     };
     for (entity, runners, token) in reactor_map.0.iter_mut() {
         let mut request_cancel = false;
-        runners.retain_mut(|runner| {
+        runners.retain_mut(|(step, runner)| {
             if request_cancel {
                 return false;
             }
+            world.insert_resource(RunningReactor(*entity, *step));
             match runner.run(world, token) {
                 RunnerIs::Completed => false,
                 RunnerIs::Running => true,
@@ -183,6 +214,7 @@ fn run_runners<L: Send + Sync + 'static>(world: &mut World) {
             world.commands().entity(*entity).despawn();
         }
     }
+    world.remove_resource::<RunningReactor>();
     world.insert_non_send_resource(reactor_map);
 }
 
@@ -243,12 +275,12 @@ mod tests {
     use crate::action::{once, wait};
     use crate::prelude::{ActionSeed, CancellationHandlers, Reactor};
     use crate::reactor::NativeReactor;
-    use crate::runner::{ReactorEntity, Runner, RunnerIs};
+    use crate::runner::{ReactorEntity, Runner, RunnerIs, RunningReactor};
     use crate::test_util::test;
     use crate::tests::test_app;
-    use bevy::app::{PostUpdate, PreStartup, Startup};
+    use bevy::app::{First, PostUpdate, PreStartup, Startup};
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{Commands, Component, Entity, Observer, Query, ResMut, Update, World};
+    use bevy::prelude::{Commands, Component, Entity, Observer, Query, Res, ResMut, Update, World};
     use bevy::prelude::{Resource, With};
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
@@ -405,4 +437,47 @@ mod tests {
             .len();
         assert_eq!(num_observer, 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn running_reactor_is_available_only_while_running() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((
+                Cancellable,
+                Reactor::schedule(|task| async move {
+                    task.will(Update, once::run(|running: Option<Res<RunningReactor>>, reactor: Query<Entity, With<Cancellable>>, mut count: ResMut<Count>| {
+                        if running.is_some_and(|running| running.0 == reactor.single()) {
+                            count.increment();
+                        }
+                    })).await;
+                }),
+            ));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        assert!(app.world().get_resource::<RunningReactor>().is_none());
+    }
+
+    #[test]
+    fn running_reactor_has_step_of_runner() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|running: Res<RunningReactor>, mut count: ResMut<Count>| {
+                    if running.1 == 1 {
+                        count.increment();
+                    }
+                })).await;
+                task.will(First, once::run(|running: Res<RunningReactor>, mut count: ResMut<Count>| {
+                    if running.1 == 2 {
+                        count.increment();
+                    }
+                })).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+}
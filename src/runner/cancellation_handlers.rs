@@ -1,7 +1,8 @@
 use bevy::prelude::{Component, Event, World};
 use bevy::utils::HashMap;
 use std::fmt::Debug;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
 
 /// The cancellation handler id assigned by [`CancellationHandlers`].
 ///
@@ -14,17 +15,24 @@ pub struct CancellationId(u64);
 ///
 /// This is passed as argument in [`Runner::run`](crate::prelude::Runner::run),
 /// and the [`Reactor`](crate::prelude::Reactor) can be cancelled by despawning the entity to which it is attached.
-#[repr(transparent)]
 #[derive(Default, Component)]
-pub struct CancellationHandlers(pub(crate) HashMap<CancellationId, fn(&mut World)>);
+pub struct CancellationHandlers(pub(crate) HashMap<CancellationId, Box<dyn FnOnce(&mut World) + Send + Sync>>, Arc<AtomicBool>);
 
 impl CancellationHandlers {
     /// Register a function that will be called when [`CancellationHandlers`] is cancelled.
     #[inline]
     pub fn register(&mut self, f: fn(&mut World)) -> CancellationId {
+        self.register_once(f)
+    }
+
+    /// Register a closure that will be called when [`CancellationHandlers`] is cancelled.
+    ///
+    /// Unlike [`CancellationHandlers::register`], the closure can capture values, and is called at most once.
+    #[inline]
+    pub fn register_once(&mut self, f: impl FnOnce(&mut World) + Send + Sync + 'static) -> CancellationId {
         static ID: AtomicU64 = AtomicU64::new(0);
         let id = CancellationId(ID.fetch_add(1, Ordering::Relaxed));
-        self.0.insert(id, f);
+        self.0.insert(id, Box::new(f));
         id
     }
 
@@ -33,6 +41,21 @@ impl CancellationHandlers {
     pub fn unregister(&mut self, id: &CancellationId) {
         self.0.remove(id);
     }
+
+    /// Marks the reactor as canceled.
+    ///
+    /// This is called before the runners of the canceled reactor are dropped,
+    /// so that they can tell it apart from being dropped while the reactor keeps running.
+    #[inline]
+    pub(crate) fn mark_canceled(&self) {
+        self.1.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns the flag that becomes true once the reactor is canceled.
+    #[inline]
+    pub(crate) fn canceled_flag(&self) -> Arc<AtomicBool> {
+        self.1.clone()
+    }
 }
 
 #[repr(transparent)]